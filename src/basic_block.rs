@@ -243,6 +243,33 @@ impl<'ctx> BasicBlock<'ctx> {
         unsafe { Some(InstructionValue::new(value)) }
     }
 
+    /// Gets an iterator over the `InstructionValue`s in this `BasicBlock`, in order.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::InstructionOpcode;
+    ///
+    /// let context = Context::create();
+    /// let builder = context.create_builder();
+    /// let module = context.create_module("my_module");
+    /// let i32_type = context.i32_type();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let function = module.add_function("do_nothing", fn_type, None);
+    /// let basic_block = context.append_basic_block(function, "entry");
+    ///
+    /// builder.position_at_end(basic_block);
+    /// builder.build_alloca(i32_type, "slot");
+    /// builder.build_return(None);
+    ///
+    /// let opcodes: Vec<_> = basic_block.get_instructions().map(|instruction| instruction.get_opcode()).collect();
+    ///
+    /// assert_eq!(opcodes, [InstructionOpcode::Alloca, InstructionOpcode::Return]);
+    /// ```
+    pub fn get_instructions(self) -> InstructionIterator<'ctx> {
+        InstructionIterator::from_basic_block(self)
+    }
+
     /// Obtains the last `InstructionValue` in this `BasicBlock`, if any. A `BasicBlock` must have a last instruction to be valid.
     ///
     /// # Example
@@ -312,6 +339,9 @@ impl<'ctx> BasicBlock<'ctx> {
 
     /// Obtains the terminating `InstructionValue` in this `BasicBlock`, if any. A `BasicBlock` must have a terminating instruction to be valid.
     ///
+    /// Unlike `get_last_instruction`, this returns `None` for a block which has instructions but
+    /// is not terminated yet, so it can be used to check whether a block still needs a terminator.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::context::Context;
@@ -334,8 +364,6 @@ impl<'ctx> BasicBlock<'ctx> {
     /// ```
     // REVIEW: If we wanted the return type could be Option<Either<BasicValueEnum, InstructionValue>>
     // if getting a value over an instruction is preferable
-    // TODOC: Every BB must have a terminating instruction or else it is invalid
-    // REVIEW: Unclear how this differs from get_last_instruction
     pub fn get_terminator(self) -> Option<InstructionValue<'ctx>> {
         let value = unsafe { LLVMGetBasicBlockTerminator(self.basic_block) };
 
@@ -597,3 +625,99 @@ impl fmt::Debug for BasicBlock<'_> {
             .finish()
     }
 }
+
+/// Iterate over all `BasicBlock`s in a function.
+#[derive(Debug)]
+pub struct BasicBlockIterator<'ctx>(BasicBlockIteratorInner<'ctx>);
+
+/// Inner type so the variants are not publicly visible
+#[derive(Debug)]
+enum BasicBlockIteratorInner<'ctx> {
+    Empty,
+    Start(BasicBlock<'ctx>),
+    Previous(BasicBlock<'ctx>),
+}
+
+impl<'ctx> BasicBlockIterator<'ctx> {
+    pub(crate) fn from_function(function: FunctionValue<'ctx>) -> Self {
+        use BasicBlockIteratorInner::*;
+
+        match function.get_first_basic_block() {
+            None => Self(Empty),
+            Some(first) => Self(Start(first)),
+        }
+    }
+}
+
+impl<'ctx> Iterator for BasicBlockIterator<'ctx> {
+    type Item = BasicBlock<'ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use BasicBlockIteratorInner::*;
+
+        match self.0 {
+            Empty => None,
+            Start(first) => {
+                self.0 = Previous(first);
+
+                Some(first)
+            },
+            Previous(prev) => match prev.get_next_basic_block() {
+                Some(current) => {
+                    self.0 = Previous(current);
+
+                    Some(current)
+                },
+                None => None,
+            },
+        }
+    }
+}
+
+/// Iterate over all `InstructionValue`s in a `BasicBlock`.
+#[derive(Debug)]
+pub struct InstructionIterator<'ctx>(InstructionIteratorInner<'ctx>);
+
+/// Inner type so the variants are not publicly visible
+#[derive(Debug)]
+enum InstructionIteratorInner<'ctx> {
+    Empty,
+    Start(InstructionValue<'ctx>),
+    Previous(InstructionValue<'ctx>),
+}
+
+impl<'ctx> InstructionIterator<'ctx> {
+    fn from_basic_block(basic_block: BasicBlock<'ctx>) -> Self {
+        use InstructionIteratorInner::*;
+
+        match basic_block.get_first_instruction() {
+            None => Self(Empty),
+            Some(first) => Self(Start(first)),
+        }
+    }
+}
+
+impl<'ctx> Iterator for InstructionIterator<'ctx> {
+    type Item = InstructionValue<'ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use InstructionIteratorInner::*;
+
+        match self.0 {
+            Empty => None,
+            Start(first) => {
+                self.0 = Previous(first);
+
+                Some(first)
+            },
+            Previous(prev) => match prev.get_next_instruction() {
+                Some(current) => {
+                    self.0 = Previous(current);
+
+                    Some(current)
+                },
+                None => None,
+            },
+        }
+    }
+}
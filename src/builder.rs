@@ -53,6 +53,21 @@ use crate::{AtomicOrdering, AtomicRMWBinOp, FloatPredicate, IntPredicate};
 
 use std::marker::PhantomData;
 
+/// A `Builder` creates instructions at its current position within a `BasicBlock`.
+///
+/// A `Builder` borrows the `Context` it was created from and disposes of its underlying
+/// LLVM builder when dropped, so it may never outlive that `Context`:
+///
+/// ```compile_fail,E0505
+/// use inkwell::context::Context;
+///
+/// let context = Context::create();
+/// let builder = context.create_builder();
+///
+/// drop(context);
+///
+/// builder.get_insert_block();
+/// ```
 #[derive(Debug)]
 pub struct Builder<'ctx> {
     builder: LLVMBuilderRef,
@@ -148,6 +163,13 @@ impl<'ctx> Builder<'ctx> {
     ///
     /// [`FunctionValue`]: crate::values::FunctionValue
     ///
+    /// Calls to functions returning void are left unnamed, and their `CallSiteValue` has no
+    /// basic value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of arguments doesn't match the number of parameters of the callee.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -162,11 +184,10 @@ impl<'ctx> Builder<'ctx> {
     /// let fn_value = module.add_function("ret", fn_type, None);
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_arg = fn_value.get_first_param().unwrap();
-    /// let md_string = context.metadata_string("a metadata");
     ///
     /// builder.position_at_end(entry);
     ///
-    /// let ret_val = builder.build_call(fn_value, &[i32_arg.into(), md_string.into()], "call")
+    /// let ret_val = builder.build_call(fn_value, &[i32_arg.into()], "call")
     ///     .try_as_basic_value()
     ///     .left()
     ///     .unwrap();
@@ -181,6 +202,8 @@ impl<'ctx> Builder<'ctx> {
         let callable_value = function.into();
         let fn_val_ref = callable_value.as_value_ref();
 
+        assert_call_arg_count(unsafe { FunctionType::new(callable_value.as_type_ref()) }, args.len());
+
         // LLVM gets upset when void return calls are named because they don't return anything
         let name = if callable_value.returns_void() { "" } else { name };
 
@@ -214,6 +237,10 @@ impl<'ctx> Builder<'ctx> {
     /// Builds a function call instruction. The function being called is known at compile time. If
     /// you want to call a function pointer, see [Builder::build_indirect_call].
     ///
+    /// # Panics
+    ///
+    /// Panics if the number of arguments doesn't match the number of parameters of the callee.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -228,11 +255,10 @@ impl<'ctx> Builder<'ctx> {
     /// let fn_value = module.add_function("ret", fn_type, None);
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_arg = fn_value.get_first_param().unwrap();
-    /// let md_string = context.metadata_string("a metadata");
     ///
     /// builder.position_at_end(entry);
     ///
-    /// let ret_val = builder.build_call(fn_value, &[i32_arg.into(), md_string.into()], "call")
+    /// let ret_val = builder.build_call(fn_value, &[i32_arg.into()], "call")
     ///     .try_as_basic_value()
     ///     .left()
     ///     .unwrap();
@@ -252,6 +278,10 @@ impl<'ctx> Builder<'ctx> {
     /// Call a function pointer. Because a pointer does not carry a type, the type of the function
     /// must be specified explicitly.
     ///
+    /// # Panics
+    ///
+    /// Panics if the number of arguments doesn't match the number of parameters of the callee.
+    ///
     /// See [Context::create_inline_asm] for a practical example. Basic usage looks like this:
     ///
     /// ```no_run
@@ -266,12 +296,11 @@ impl<'ctx> Builder<'ctx> {
     /// let fn_value = module.add_function("ret", fn_type, None);
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_arg = fn_value.get_first_param().unwrap();
-    /// let md_string = context.metadata_string("a metadata");
     ///
     /// builder.position_at_end(entry);
     ///
     /// let function_pointer = fn_value.as_global_value().as_pointer_value();
-    /// let ret_val = builder.build_indirect_call(fn_value.get_type(), function_pointer, &[i32_arg.into(), md_string.into()], "call")
+    /// let ret_val = builder.build_indirect_call(fn_value.get_type(), function_pointer, &[i32_arg.into()], "call")
     ///     .try_as_basic_value()
     ///     .left()
     ///     .unwrap();
@@ -298,6 +327,8 @@ impl<'ctx> Builder<'ctx> {
         args: &[BasicMetadataValueEnum<'ctx>],
         name: &str,
     ) -> CallSiteValue<'ctx> {
+        assert_call_arg_count(function_type, args.len());
+
         // LLVM gets upset when void return calls are named because they don't return anything
         let name = match function_type.get_return_type() {
             None => "",
@@ -855,7 +886,13 @@ impl<'ctx> Builder<'ctx> {
         unsafe { InstructionValue::new(val) }
     }
 
-    // REVIEW: Doesn't GEP work on array too?
+    /// Builds a GEP (getelementptr) instruction, which computes the address of an element
+    /// within an aggregate without accessing memory.
+    ///
+    /// The first index steps over whole values of the pointee type, as if `ptr` pointed into
+    /// an array of them, so it is usually zero. Each subsequent index then selects an element
+    /// of the struct, array or vector reached so far. Struct indices must be constant `i32`s.
+    ///
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
     #[llvm_versions(4.0..=14.0)]
     pub unsafe fn build_gep(
@@ -879,7 +916,13 @@ impl<'ctx> Builder<'ctx> {
         PointerValue::new(value)
     }
 
-    // REVIEW: Doesn't GEP work on array too?
+    /// Builds a GEP (getelementptr) instruction, which computes the address of an element
+    /// within an aggregate without accessing memory.
+    ///
+    /// The first index steps over whole values of the pointee type, as if `ptr` pointed into
+    /// an array of them, so it is usually zero. Each subsequent index then selects an element
+    /// of the struct, array or vector reached so far. Struct indices must be constant `i32`s.
+    ///
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
     #[llvm_versions(15.0..=latest)]
     pub unsafe fn build_gep<T: BasicType<'ctx>>(
@@ -905,8 +948,10 @@ impl<'ctx> Builder<'ctx> {
         PointerValue::new(value)
     }
 
-    // REVIEW: Doesn't GEP work on array too?
     // REVIEW: This could be merge in with build_gep via a in_bounds: bool param
+    /// Builds an inbounds GEP, which is like `build_gep` except that the result is poison
+    /// if the computed address falls outside of the allocated object `ptr` points into.
+    ///
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
     #[llvm_versions(4.0..=14.0)]
     pub unsafe fn build_in_bounds_gep(
@@ -930,8 +975,10 @@ impl<'ctx> Builder<'ctx> {
         PointerValue::new(value)
     }
 
-    // REVIEW: Doesn't GEP work on array too?
     // REVIEW: This could be merge in with build_gep via a in_bounds: bool param
+    /// Builds an inbounds GEP, which is like `build_gep` except that the result is poison
+    /// if the computed address falls outside of the allocated object `ptr` points into.
+    ///
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
     #[llvm_versions(15.0..=latest)]
     pub unsafe fn build_in_bounds_gep<T: BasicType<'ctx>>(
@@ -1176,8 +1223,9 @@ impl<'ctx> Builder<'ctx> {
     // SubTypes: Maybe this should return PhiValue<T>? That way we could force incoming values to be of T::Value?
     // That is, assuming LLVM complains about different phi types.. which I imagine it would. But this would get
     // tricky with VoidType since it has no instance value?
-    // TODOC: Phi Instruction(s) must be first instruction(s) in a BasicBlock.
-    // REVIEW: Not sure if we can enforce the above somehow via types.
+    // REVIEW: Not sure if we can enforce phis coming first in a block somehow via types.
+    /// Builds a phi node of type `type_`, whose incoming values are added afterwards with
+    /// `PhiValue::add_incoming`. Phi nodes must be the first instruction(s) in a `BasicBlock`.
     pub fn build_phi<T: BasicType<'ctx>>(&self, type_: T, name: &str) -> PhiValue<'ctx> {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildPhi(self.builder, type_.as_type_ref(), c_string.as_ptr()) };
@@ -1296,7 +1344,33 @@ impl<'ctx> Builder<'ctx> {
         unsafe { BasicValueEnum::new(value) }
     }
 
-    // TODOC: Stack allocation
+    /// Builds a stack allocation for a single value of type `ty`, returning a pointer to it.
+    /// The memory is freed automatically when the function returns.
+    ///
+    /// Allocas are best placed in the entry block, where LLVM can promote them to registers.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// // Builds a function which stores a 7 in a stack slot.
+    /// let context = Context::create();
+    /// let module = context.create_module("ret");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("ret", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let slot = builder.build_alloca(i32_type, "slot");
+    ///
+    /// builder.build_store(slot, i32_type.const_int(7, false));
+    /// builder.build_return(None);
+    /// ```
     pub fn build_alloca<T: BasicType<'ctx>>(&self, ty: T, name: &str) -> PointerValue<'ctx> {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildAlloca(self.builder, ty.as_type_ref(), c_string.as_ptr()) };
@@ -1304,7 +1378,8 @@ impl<'ctx> Builder<'ctx> {
         unsafe { PointerValue::new(value) }
     }
 
-    // TODOC: Stack allocation
+    /// Builds a stack allocation for `size` consecutive values of type `ty`, returning a
+    /// pointer to the first one.
     pub fn build_array_alloca<T: BasicType<'ctx>>(
         &self,
         ty: T,
@@ -1401,7 +1476,8 @@ impl<'ctx> Builder<'ctx> {
     /// Alignment arguments are specified in bytes, and should always be
     /// both a power of 2 and under 2^64.
     ///
-    /// The final argument should be a pointer-sized integer.
+    /// The value to fill memory with should be an `i8`, and the final
+    /// argument should be a pointer-sized integer.
     ///
     /// [`TargetData::ptr_sized_int_type_in_context`](https://thedan64.github.io/inkwell/inkwell/targets/struct.TargetData.html#method.ptr_sized_int_type_in_context) will get you one of those.
     #[llvm_versions(8.0..=latest)]
@@ -1413,7 +1489,7 @@ impl<'ctx> Builder<'ctx> {
         size: IntValue<'ctx>,
     ) -> Result<PointerValue<'ctx>, &'static str> {
         if !is_alignment_ok(dest_align_bytes) {
-            return Err("The src_align_bytes argument to build_memmove was not a power of 2 under 2^64.");
+            return Err("The dest_align_bytes argument to build_memset was not a power of 2 under 2^64.");
         }
 
         let value = unsafe {
@@ -1429,7 +1505,32 @@ impl<'ctx> Builder<'ctx> {
         unsafe { Ok(PointerValue::new(value)) }
     }
 
-    // TODOC: Heap allocation
+    /// Builds a call to `malloc` allocating space for one value of type `ty`, and returns a
+    /// pointer to it. A declaration of `malloc` is added to the module if needed, so the
+    /// target must provide one when the module is linked or JIT compiled.
+    ///
+    /// Returns an `Err` if `ty` is unsized.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("heap");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let function = module.add_function("boxed", context.void_type().fn_type(&[], false), None);
+    /// let entry = context.append_basic_block(function, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let ptr = builder.build_malloc(i32_type, "ptr").unwrap();
+    ///
+    /// builder.build_store(ptr, i32_type.const_int(42, false));
+    /// builder.build_free(ptr);
+    /// builder.build_return(None);
+    /// ```
     pub fn build_malloc<T: BasicType<'ctx>>(&self, ty: T, name: &str) -> Result<PointerValue<'ctx>, &'static str> {
         // LLVMBulidMalloc segfaults if ty is unsized
         if !ty.is_sized() {
@@ -1443,7 +1544,10 @@ impl<'ctx> Builder<'ctx> {
         unsafe { Ok(PointerValue::new(value)) }
     }
 
-    // TODOC: Heap allocation
+    /// Builds a call to `malloc` allocating space for `size` values of type `ty`, and returns a
+    /// pointer to the first of them. Like `build_malloc`, this declares `malloc` if needed.
+    ///
+    /// Returns an `Err` if `ty` is unsized.
     pub fn build_array_malloc<T: BasicType<'ctx>>(
         &self,
         ty: T,
@@ -1463,6 +1567,8 @@ impl<'ctx> Builder<'ctx> {
         unsafe { Ok(PointerValue::new(value)) }
     }
 
+    /// Builds a call to `free` releasing memory allocated by `build_malloc` or
+    /// `build_array_malloc`. A declaration of `free` is added to the module if needed.
     // SubType: <P>(&self, ptr: PointerValue<P>) -> InstructionValue {
     pub fn build_free(&self, ptr: PointerValue<'ctx>) -> InstructionValue<'ctx> {
         unsafe { InstructionValue::new(LLVMBuildFree(self.builder, ptr.as_value_ref())) }
@@ -1481,6 +1587,8 @@ impl<'ctx> Builder<'ctx> {
         }
     }
 
+    /// Gets the `BasicBlock` this builder is positioned in, or `None` if it has not been
+    /// positioned yet or its position was cleared.
     pub fn get_insert_block(&self) -> Option<BasicBlock<'ctx>> {
         unsafe { BasicBlock::new(LLVMGetInsertBlock(self.builder)) }
     }
@@ -1488,6 +1596,7 @@ impl<'ctx> Builder<'ctx> {
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
     // SubType: <I: IntSubType>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
     //     if I::sign() == Unsigned { LLVMBuildUDiv() } else { LLVMBuildSDiv() }
+    /// Builds an unsigned integer division. Dividing by zero is undefined behavior.
     pub fn build_int_unsigned_div<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildUDiv(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1497,6 +1606,7 @@ impl<'ctx> Builder<'ctx> {
 
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds a signed integer division, rounding towards zero. Dividing by zero is undefined behavior.
     pub fn build_int_signed_div<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildSDiv(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1516,6 +1626,7 @@ impl<'ctx> Builder<'ctx> {
 
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an unsigned integer remainder. Taking the remainder of a division by zero is undefined behavior.
     pub fn build_int_unsigned_rem<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildURem(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1525,6 +1636,8 @@ impl<'ctx> Builder<'ctx> {
 
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds a signed integer remainder, whose sign follows the dividend like Rust's `%`, so `-7 % 3` is `-1`.
+    /// Taking the remainder of a division by zero, or of the minimum signed value by `-1`, is undefined behavior.
     pub fn build_int_signed_rem<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildSRem(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1532,6 +1645,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
+    /// Builds a sign extension, widening `int_value` to `int_type` by copying its sign bit.
     pub fn build_int_s_extend<T: IntMathValue<'ctx>>(&self, int_value: T, int_type: T::BaseType, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe {
@@ -1624,6 +1738,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
+    /// Builds a zero extension, widening `int_value` to `int_type` by filling the new high bits with zeros.
     pub fn build_int_z_extend<T: IntMathValue<'ctx>>(&self, int_value: T, int_type: T::BaseType, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe {
@@ -1657,6 +1772,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
+    /// Builds an integer truncation, narrowing `int_value` to `int_type` by dropping its high bits.
     pub fn build_int_truncate<T: IntMathValue<'ctx>>(&self, int_value: T, int_type: T::BaseType, name: &str) -> T {
         let c_string = to_c_str(name);
 
@@ -1692,6 +1808,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
+    /// Builds a floating point remainder, whose sign follows the dividend like C's `fmod`.
     pub fn build_float_rem<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildFRem(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1700,6 +1817,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // REVIEW: Consolidate these two casts into one via subtypes
+    /// Builds a conversion from a float to an unsigned integer, rounding towards zero.
     pub fn build_float_to_unsigned_int<T: FloatMathValue<'ctx>>(
         &self,
         float: T,
@@ -1719,6 +1837,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { <<T::BaseType as FloatMathType>::MathConvType as IntMathType>::ValueType::new(value) }
     }
 
+    /// Builds a conversion from a float to a signed integer, rounding towards zero.
     pub fn build_float_to_signed_int<T: FloatMathValue<'ctx>>(
         &self,
         float: T,
@@ -1739,6 +1858,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // REVIEW: Consolidate these two casts into one via subtypes
+    /// Builds a conversion from an unsigned integer to a float.
     pub fn build_unsigned_int_to_float<T: IntMathValue<'ctx>>(
         &self,
         int: T,
@@ -1758,6 +1878,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { <<T::BaseType as IntMathType>::MathConvType as FloatMathType>::ValueType::new(value) }
     }
 
+    /// Builds a conversion from a signed integer to a float.
     pub fn build_signed_int_to_float<T: IntMathValue<'ctx>>(
         &self,
         int: T,
@@ -1777,6 +1898,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { <<T::BaseType as IntMathType>::MathConvType as FloatMathType>::ValueType::new(value) }
     }
 
+    /// Builds a float truncation to the narrower `float_type`.
     pub fn build_float_trunc<T: FloatMathValue<'ctx>>(&self, float: T, float_type: T::BaseType, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe {
@@ -1791,6 +1913,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
+    /// Builds a float extension to the wider `float_type`.
     pub fn build_float_ext<T: FloatMathValue<'ctx>>(&self, float: T, float_type: T::BaseType, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe {
@@ -1857,6 +1980,7 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
+    /// Builds a floating point division.
     pub fn build_float_div<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildFDiv(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1865,6 +1989,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an integer addition. Overflow wraps around.
     pub fn build_int_add<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildAdd(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1874,6 +1999,9 @@ impl<'ctx> Builder<'ctx> {
 
     // REVIEW: Possibly incorperate into build_int_add via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an integer addition marked `nsw` (no signed wrap). If the signed result overflows it
+    /// is poison, which lets the optimizer assume it never does. The `nuw` (no unsigned wrap)
+    /// builders work the same way for unsigned overflow.
     pub fn build_int_nsw_add<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNSWAdd(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1883,6 +2011,7 @@ impl<'ctx> Builder<'ctx> {
 
     // REVIEW: Possibly incorperate into build_int_add via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an integer addition marked `nuw`.
     pub fn build_int_nuw_add<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNUWAdd(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1890,7 +2019,11 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
+    // REVIEW: Fast-math flags (nnan, ninf, nsz, arcp, contract, afn, reassoc, fast) can't be set on
+    // these instructions yet: the C API only gained LLVMSetFastMathFlags in LLVM 18. Until then the
+    // "unsafe-fp-math" family of function attributes is the only way to relax FP semantics.
     // SubType: <F>(&self, lhs: &FloatValue<F>, rhs: &FloatValue<F>, name: &str) -> FloatValue<F> {
+    /// Builds a floating point addition.
     pub fn build_float_add<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildFAdd(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1899,6 +2032,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: (&self, lhs: &IntValue<bool>, rhs: &IntValue<bool>, name: &str) -> IntValue<bool> {
+    /// Builds a bitwise exclusive or of two integers, or of two vectors of integers.
     pub fn build_xor<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildXor(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1907,6 +2041,8 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: (&self, lhs: &IntValue<bool>, rhs: &IntValue<bool>, name: &str) -> IntValue<bool> {
+    /// Builds a bitwise and of two integers, or of two vectors of integers. This is commonly used for masking,
+    /// such as `x & 0xFF` to keep only the low byte of `x`.
     pub fn build_and<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildAnd(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -1915,6 +2051,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: (&self, lhs: &IntValue<bool>, rhs: &IntValue<bool>, name: &str) -> IntValue<bool> {
+    /// Builds a bitwise or of two integers, or of two vectors of integers.
     pub fn build_or<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildOr(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2050,6 +2187,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an integer subtraction. Overflow wraps around.
     pub fn build_int_sub<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildSub(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2058,6 +2196,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // REVIEW: Possibly incorperate into build_int_sub via flag param
+    /// Builds an integer subtraction marked `nsw`.
     pub fn build_int_nsw_sub<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNSWSub(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2067,6 +2206,7 @@ impl<'ctx> Builder<'ctx> {
 
     // REVIEW: Possibly incorperate into build_int_sub via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an integer subtraction marked `nuw`.
     pub fn build_int_nuw_sub<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNUWSub(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2075,6 +2215,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <F>(&self, lhs: &FloatValue<F>, rhs: &FloatValue<F>, name: &str) -> FloatValue<F> {
+    /// Builds a floating point subtraction.
     pub fn build_float_sub<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildFSub(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2083,6 +2224,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an integer multiplication. Overflow wraps around.
     pub fn build_int_mul<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildMul(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2092,6 +2234,7 @@ impl<'ctx> Builder<'ctx> {
 
     // REVIEW: Possibly incorperate into build_int_mul via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an integer multiplication marked `nsw`.
     pub fn build_int_nsw_mul<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNSWMul(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2101,6 +2244,7 @@ impl<'ctx> Builder<'ctx> {
 
     // REVIEW: Possibly incorperate into build_int_mul via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
+    /// Builds an integer multiplication marked `nuw`.
     pub fn build_int_nuw_mul<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNUWMul(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2109,6 +2253,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <F>(&self, lhs: &FloatValue<F>, rhs: &FloatValue<F>, name: &str) -> FloatValue<F> {
+    /// Builds a floating point multiplication.
     pub fn build_float_mul<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildFMul(self.builder, lhs.as_value_ref(), rhs.as_value_ref(), c_string.as_ptr()) };
@@ -2146,6 +2291,31 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
+    /// Builds an integer comparison, producing an `i1` (or a vector of `i1`s for vector operands)
+    /// which is true when `lhs` and `rhs` satisfy `op`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::IntPredicate;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("cmp");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = context.bool_type().fn_type(&[i32_type.into(), i32_type.into()], false);
+    /// let fn_value = module.add_function("is_greater", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let lhs = fn_value.get_nth_param(0).unwrap().into_int_value();
+    /// let rhs = fn_value.get_nth_param(1).unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let is_greater = builder.build_int_compare(IntPredicate::SGT, lhs, rhs, "is_greater");
+    ///
+    /// builder.build_return(Some(&is_greater));
+    /// ```
     // SubType: <I>(&self, op, lhs: &IntValue<I>, rhs: &IntValue<I>, name) -> IntValue<bool> { ?
     // Note: we need a way to get an appropriate return type, since this method's return value
     // is always a bool (or vector of bools), not necessarily the same as the input value
@@ -2171,6 +2341,8 @@ impl<'ctx> Builder<'ctx> {
         unsafe { <T::BaseType as IntMathType<'ctx>>::ValueType::new(value) }
     }
 
+    /// Builds a floating point comparison, producing an `i1` (or a vector of `i1`s for vector
+    /// operands). Ordered predicates are false if either operand is NaN, unordered ones are true.
     // SubType: <F>(&self, op, lhs: &FloatValue<F>, rhs: &FloatValue<F>, name) -> IntValue<bool> { ?
     // Note: see comment on build_int_compare regarding return value type
     pub fn build_float_compare<T: FloatMathValue<'ctx>>(
@@ -2195,12 +2367,45 @@ impl<'ctx> Builder<'ctx> {
         unsafe { <<T::BaseType as FloatMathType>::MathConvType as IntMathType>::ValueType::new(value) }
     }
 
+    /// Builds a branch which always jumps to `destination_block`. Like any terminator, it must
+    /// be the last instruction of its block.
     pub fn build_unconditional_branch(&self, destination_block: BasicBlock<'ctx>) -> InstructionValue<'ctx> {
         let value = unsafe { LLVMBuildBr(self.builder, destination_block.basic_block) };
 
         unsafe { InstructionValue::new(value) }
     }
 
+    /// Builds a branch which jumps to `then_block` if the `i1` `comparison` is true, and to
+    /// `else_block` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::IntPredicate;
+    ///
+    /// // fn abs(x: i32) -> i32 { if x < 0 { -x } else { x } }
+    /// let context = Context::create();
+    /// let module = context.create_module("abs");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("abs", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let negative = context.append_basic_block(fn_value, "negative");
+    /// let positive = context.append_basic_block(fn_value, "positive");
+    /// let x = fn_value.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let is_negative = builder.build_int_compare(IntPredicate::SLT, x, i32_type.const_zero(), "is_negative");
+    ///
+    /// builder.build_conditional_branch(is_negative, negative, positive);
+    /// builder.position_at_end(negative);
+    /// builder.build_return(Some(&builder.build_int_neg(x, "neg")));
+    /// builder.position_at_end(positive);
+    /// builder.build_return(Some(&x));
+    /// ```
     pub fn build_conditional_branch(
         &self,
         comparison: IntValue<'ctx>,
@@ -2219,6 +2424,9 @@ impl<'ctx> Builder<'ctx> {
         unsafe { InstructionValue::new(value) }
     }
 
+    /// Builds an indirect branch to the block whose address is `address`, as obtained from
+    /// `BasicBlock::get_address`. Every block which may be jumped to must be listed in
+    /// `destinations`, otherwise the behavior is undefined.
     pub fn build_indirect_branch<BV: BasicValue<'ctx>>(
         &self,
         address: BV,
@@ -2234,6 +2442,8 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <I>(&self, value: &IntValue<I>, name) -> IntValue<I> {
+    /// Builds an integer negation, which LLVM represents as a subtraction from zero.
+    /// Overflow wraps around, so negating the minimum signed value gives it back.
     pub fn build_int_neg<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNeg(self.builder, value.as_value_ref(), c_string.as_ptr()) };
@@ -2243,6 +2453,8 @@ impl<'ctx> Builder<'ctx> {
 
     // REVIEW: Possibly incorperate into build_int_neg via flag and subtypes
     // SubType: <I>(&self, value: &IntValue<I>, name) -> IntValue<I> {
+    /// Builds an integer negation marked `nsw`, so negating the minimum signed value
+    /// produces a poison value.
     pub fn build_int_nsw_neg<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNSWNeg(self.builder, value.as_value_ref(), c_string.as_ptr()) };
@@ -2251,6 +2463,8 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <I>(&self, value: &IntValue<I>, name) -> IntValue<I> {
+    /// Builds an integer negation marked `nuw`, so negating any value but zero produces
+    /// a poison value.
     pub fn build_int_nuw_neg<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNUWNeg(self.builder, value.as_value_ref(), c_string.as_ptr()) };
@@ -2259,6 +2473,8 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <F>(&self, value: &FloatValue<F>, name) -> FloatValue<F> {
+    /// Builds a floating point negation, which flips the sign bit. Unlike subtracting from
+    /// zero, this also turns `0.0` into `-0.0`.
     pub fn build_float_neg<T: FloatMathValue<'ctx>>(&self, value: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildFNeg(self.builder, value.as_value_ref(), c_string.as_ptr()) };
@@ -2267,6 +2483,8 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <I>(&self, value: &IntValue<I>, name) -> IntValue<bool> { ?
+    /// Builds a bitwise not of an integer, flipping all of its bits. LLVM represents this as
+    /// an `xor` with `-1`.
     pub fn build_not<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> T {
         let c_string = to_c_str(name);
         let value = unsafe { LLVMBuildNot(self.builder, value.as_value_ref(), c_string.as_ptr()) };
@@ -2274,16 +2492,76 @@ impl<'ctx> Builder<'ctx> {
         unsafe { T::new(value) }
     }
 
-    // REVIEW: What if instruction and basic_block are completely unrelated?
+    /// Positions the builder before an instruction of a `BasicBlock`, so that subsequently
+    /// built instructions are inserted ahead of it. This is the same as `position_before`
+    /// when the instruction belongs to the `BasicBlock`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instruction does not belong to the given `BasicBlock`.
     // It'd be great if we could get the BB from the instruction behind the scenes
     pub fn position_at(&self, basic_block: BasicBlock<'ctx>, instruction: &InstructionValue<'ctx>) {
+        assert_eq!(
+            instruction.get_parent(),
+            Some(basic_block),
+            "Instruction must belong to the given basic block"
+        );
+
         unsafe { LLVMPositionBuilder(self.builder, basic_block.basic_block, instruction.as_value_ref()) }
     }
 
+    /// Positions the builder before an instruction, so that subsequently built instructions
+    /// are inserted ahead of it in its `BasicBlock`. This is useful for instrumenting
+    /// existing code, such as adding instructions before a return.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("f", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let x = fn_value.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let ret = builder.build_return(Some(&x));
+    ///
+    /// builder.position_before(&ret);
+    /// builder.build_int_add(x, x, "doubled");
+    ///
+    /// assert_eq!(entry.get_last_instruction(), Some(ret));
+    /// assert_eq!(builder.get_insert_block(), Some(entry));
+    /// ```
     pub fn position_before(&self, instruction: &InstructionValue<'ctx>) {
         unsafe { LLVMPositionBuilderBefore(self.builder, instruction.as_value_ref()) }
     }
 
+    /// Positions the builder after the last instruction of a `BasicBlock`, so that
+    /// subsequently built instructions are appended to it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("f", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_return(None);
+    ///
+    /// assert_eq!(builder.get_insert_block(), Some(entry));
+    /// ```
     pub fn position_at_end(&self, basic_block: BasicBlock<'ctx>) {
         unsafe {
             LLVMPositionBuilderAtEnd(self.builder, basic_block.basic_block);
@@ -2293,6 +2571,8 @@ impl<'ctx> Builder<'ctx> {
     /// Builds an extract value instruction which extracts a `BasicValueEnum`
     /// from a struct or array.
     ///
+    /// Returns `None` if `index` is out of bounds for the aggregate type.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -2368,7 +2648,10 @@ impl<'ctx> Builder<'ctx> {
     }
 
     /// Builds an insert value instruction which inserts a `BasicValue` into a struct
-    /// or array and returns the resulting aggregate value.
+    /// or array and returns the resulting aggregate value. The original aggregate is
+    /// left unchanged, so inserts are usually chained starting from an undef value.
+    ///
+    /// Returns `None` if `index` is out of bounds for the aggregate type.
     ///
     /// # Example
     ///
@@ -2543,18 +2826,46 @@ impl<'ctx> Builder<'ctx> {
         unsafe { VectorValue::new(value) }
     }
 
+    /// Builds an unreachable terminator, which tells the optimizer that control flow can never
+    /// reach this point. Actually reaching it is undefined behavior.
     pub fn build_unreachable(&self) -> InstructionValue<'ctx> {
         let val = unsafe { LLVMBuildUnreachable(self.builder) };
 
         unsafe { InstructionValue::new(val) }
     }
 
+    /// Builds a fence instruction, which orders memory operations around it according to
+    /// `atomic_ordering`. The ordering must be one of `Acquire`, `Release`, `AcquireRelease`
+    /// or `SequentiallyConsistent` for the module to verify. A non-zero `single_thread`
+    /// only synchronizes with code running in the same thread, such as signal handlers.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AtomicOrdering;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("fence");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let function = module.add_function("fence", fn_type, None);
+    /// let entry = context.append_basic_block(function, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_fence(AtomicOrdering::SequentiallyConsistent, 0, "");
+    /// builder.build_return(None);
+    /// ```
     // REVIEW: Not sure if this should return InstructionValue or an actual value
-    // TODO: Better name for num?
-    pub fn build_fence(&self, atomic_ordering: AtomicOrdering, num: i32, name: &str) -> InstructionValue<'ctx> {
+    pub fn build_fence(
+        &self,
+        atomic_ordering: AtomicOrdering,
+        single_thread: i32,
+        name: &str,
+    ) -> InstructionValue<'ctx> {
         let c_string = to_c_str(name);
 
-        let val = unsafe { LLVMBuildFence(self.builder, atomic_ordering.into(), num, c_string.as_ptr()) };
+        let val = unsafe { LLVMBuildFence(self.builder, atomic_ordering.into(), single_thread, c_string.as_ptr()) };
 
         unsafe { InstructionValue::new(val) }
     }
@@ -2584,6 +2895,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <I, P>(&self, int: &IntValue<I>, ptr_type: &PointerType<P>, name) -> PointerValue<P> {
+    /// Builds a conversion from an integer to a pointer of type `ptr_type`.
     pub fn build_int_to_ptr<T: IntMathValue<'ctx>>(
         &self,
         int: T,
@@ -2605,6 +2917,7 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <I, P>(&self, ptr: &PointerValue<P>, int_type: &IntType<I>, name) -> IntValue<I> {
+    /// Builds a conversion from a pointer to an integer of type `int_type`.
     pub fn build_ptr_to_int<T: PointerMathValue<'ctx>>(
         &self,
         ptr: T,
@@ -2625,19 +2938,83 @@ impl<'ctx> Builder<'ctx> {
         unsafe { <<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(value) }
     }
 
+    /// Clears the position of this `Builder`, so that it is no longer positioned in any
+    /// `BasicBlock` until it is positioned again.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("f", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.clear_insertion_position();
+    ///
+    /// assert!(builder.get_insert_block().is_none());
+    /// ```
     pub fn clear_insertion_position(&self) {
         unsafe { LLVMClearInsertionPosition(self.builder) }
     }
 
     // REVIEW: Returning InstructionValue is the safe move here; but if the value means something
     // (IE the result of the switch) it should probably return BasicValueEnum?
-    // SubTypes: I think value and case values must be the same subtype (maybe). Case value might need to be constants
+    /// Builds a switch terminator, which branches to the block of the case whose value equals
+    /// `value`, or to `else_block` if none match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a case value is not a constant or has a different type than `value`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("switch");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let function = module.add_function("is_one_or_two", fn_type, None);
+    /// let entry = context.append_basic_block(function, "entry");
+    /// let matched = context.append_basic_block(function, "matched");
+    /// let default = context.append_basic_block(function, "default");
+    /// let value = function.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_switch(
+    ///     value,
+    ///     default,
+    ///     &[(i32_type.const_int(1, false), matched), (i32_type.const_int(2, false), matched)],
+    /// );
+    ///
+    /// builder.position_at_end(matched);
+    /// builder.build_return(Some(&i32_type.const_int(1, false)));
+    ///
+    /// builder.position_at_end(default);
+    /// builder.build_return(Some(&i32_type.const_zero()));
+    /// ```
     pub fn build_switch(
         &self,
         value: IntValue<'ctx>,
         else_block: BasicBlock<'ctx>,
         cases: &[(IntValue<'ctx>, BasicBlock<'ctx>)],
     ) -> InstructionValue<'ctx> {
+        for &(case_value, _) in cases {
+            assert!(case_value.is_const(), "Switch case values must be constants");
+            assert_eq!(
+                case_value.get_type(),
+                value.get_type(),
+                "Switch case values must have the type of the switched value"
+            );
+        }
+
         let switch_value = unsafe {
             LLVMBuildSwitch(
                 self.builder,
@@ -2654,6 +3031,36 @@ impl<'ctx> Builder<'ctx> {
         unsafe { InstructionValue::new(switch_value) }
     }
 
+    /// Builds a select instruction, which evaluates to `then` if `condition` is true and to
+    /// `else_` otherwise, without branching. A vector of bools selects element-wise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `then` and `else_` have different types.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::IntPredicate;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("select");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    /// let function = module.add_function("max", fn_type, None);
+    /// let entry = context.append_basic_block(function, "entry");
+    /// let a = function.get_nth_param(0).unwrap().into_int_value();
+    /// let b = function.get_nth_param(1).unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let a_is_greater = builder.build_int_compare(IntPredicate::SGT, a, b, "a_is_greater");
+    /// let max = builder.build_select(a_is_greater, a, b, "max");
+    ///
+    /// builder.build_return(Some(&max));
+    /// ```
     // SubTypes: condition can only be IntValue<bool> or VectorValue<IntValue<Bool>>
     pub fn build_select<BV: BasicValue<'ctx>, IMV: IntMathValue<'ctx>>(
         &self,
@@ -2662,6 +3069,12 @@ impl<'ctx> Builder<'ctx> {
         else_: BV,
         name: &str,
     ) -> BasicValueEnum<'ctx> {
+        assert_eq!(
+            then.as_basic_value_enum().get_type(),
+            else_.as_basic_value_enum().get_type(),
+            "Select operands must have the same type"
+        );
+
         let c_string = to_c_str(name);
         let value = unsafe {
             LLVMBuildSelect(
@@ -2676,6 +3089,39 @@ impl<'ctx> Builder<'ctx> {
         unsafe { BasicValueEnum::new(value) }
     }
 
+    /// Builds a freeze instruction. If `value` is undef or poison, the result is an arbitrary
+    /// but fixed value of its type, otherwise it is `value` itself. This stops poison from
+    /// propagating into code where it would cause undefined behavior, such as a branch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("freeze");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[], false);
+    /// let function = module.add_function("arbitrary", fn_type, None);
+    /// let entry = context.append_basic_block(function, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let frozen = builder.build_freeze(i32_type.get_undef(), "frozen");
+    ///
+    /// builder.build_return(Some(&frozen));
+    /// ```
+    #[llvm_versions(10.0..=latest)]
+    pub fn build_freeze<BV: BasicValue<'ctx>>(&self, value: BV, name: &str) -> BasicValueEnum<'ctx> {
+        use llvm_sys::core::LLVMBuildFreeze;
+
+        let c_string = to_c_str(name);
+        let value = unsafe { LLVMBuildFreeze(self.builder, value.as_value_ref(), c_string.as_ptr()) };
+
+        unsafe { BasicValueEnum::new(value) }
+    }
+
     // The unsafety of this function should be fixable with subtypes. See GH #32
     pub unsafe fn build_global_string(&self, value: &str, name: &str) -> GlobalValue<'ctx> {
         let c_string_value = to_c_str(value);
@@ -2687,6 +3133,38 @@ impl<'ctx> Builder<'ctx> {
 
     // REVIEW: Does this similar fn have the same issue build_global_string does? If so, mark as unsafe
     // and fix with subtypes.
+    /// Creates a private, constant, NUL terminated global holding `value`, and returns a value
+    /// pointing to its first character, suitable to pass to C functions such as `puts`.
+    ///
+    /// `value` ends at its first NUL byte, if any, and a terminating NUL is always added, so
+    /// `"hello"` and `"hello\0"` produce the same string. The builder must be positioned in a
+    /// function, as the global is added to its module.
+    ///
+    /// On LLVM versions with typed pointers the returned value is a constant expression rather
+    /// than an actual global, so only use it via `GlobalValue::as_pointer_value`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("hello");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::default());
+    /// let puts = module.add_function("puts", i32_type.fn_type(&[i8_ptr_type.into()], false), None);
+    /// let main = module.add_function("main", i32_type.fn_type(&[], false), None);
+    /// let entry = context.append_basic_block(main, "entry");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let hello = builder.build_global_string_ptr("hello", "hello");
+    ///
+    /// builder.build_call(puts, &[hello.as_pointer_value().into()], "call");
+    /// builder.build_return(Some(&i32_type.const_zero()));
+    /// ```
     pub fn build_global_string_ptr(&self, value: &str, name: &str) -> GlobalValue<'ctx> {
         let c_string_value = to_c_str(value);
         let c_string_name = to_c_str(name);
@@ -2695,7 +3173,38 @@ impl<'ctx> Builder<'ctx> {
         unsafe { GlobalValue::new(value) }
     }
 
-    // REVIEW: Do we need to constrain types here? subtypes?
+    /// Builds a shuffle vector instruction, which creates a vector from elements of `left` and
+    /// `right`. Each element of `mask` is an index into the concatenation of both vectors,
+    /// and the result has as many elements as `mask`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `left` and `right` have different types, or if `mask` is not a constant
+    /// vector of `i32`s.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::VectorType;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("shuffle");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let vec_type = i32_type.vec_type(2);
+    /// let fn_type = vec_type.fn_type(&[vec_type.into()], false);
+    /// let function = module.add_function("swap", fn_type, None);
+    /// let entry = context.append_basic_block(function, "entry");
+    /// let vector = function.get_first_param().unwrap().into_vector_value();
+    /// let mask = VectorType::const_vector(&[i32_type.const_int(1, false), i32_type.const_int(0, false)]);
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let swapped = builder.build_shuffle_vector(vector, vector.get_type().get_undef(), mask, "swapped");
+    ///
+    /// builder.build_return(Some(&swapped));
+    /// ```
     pub fn build_shuffle_vector(
         &self,
         left: VectorValue<'ctx>,
@@ -2703,6 +3212,21 @@ impl<'ctx> Builder<'ctx> {
         mask: VectorValue<'ctx>,
         name: &str,
     ) -> VectorValue<'ctx> {
+        assert_eq!(
+            left.get_type(),
+            right.get_type(),
+            "Shuffled vectors must have the same type"
+        );
+
+        let mask_element_type = mask.get_type().get_element_type();
+
+        assert!(
+            mask.is_const()
+                && mask_element_type.is_int_type()
+                && mask_element_type.into_int_type().get_bit_width() == 32,
+            "Shuffle mask must be a constant vector of i32"
+        );
+
         let c_string = to_c_str(name);
         let value = unsafe {
             LLVMBuildShuffleVector(
@@ -2741,6 +3265,13 @@ impl<'ctx> Builder<'ctx> {
     }
 
     /// Builds an atomicrmw instruction. It allows you to atomically modify memory.
+    /// The returned value is the one stored in memory before the modification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the bit width of `value` is not a power of two of at least 8, or
+    /// if `ptr` does not point to the type of `value`. Note that LLVM additionally requires
+    /// an ordering of at least `AtomicOrdering::Monotonic` for the module to verify.
     ///
     /// # Example
     ///
@@ -2759,7 +3290,7 @@ impl<'ctx> Builder<'ctx> {
     /// let i32_ptr_param = fn_value.get_first_param().unwrap().into_pointer_value();
     /// let builder = context.create_builder();
     /// builder.position_at_end(entry);
-    /// builder.build_atomicrmw(AtomicRMWBinOp::Add, i32_ptr_param, i32_seven, AtomicOrdering::Unordered);
+    /// builder.build_atomicrmw(AtomicRMWBinOp::Add, i32_ptr_param, i32_seven, AtomicOrdering::Monotonic);
     /// builder.build_return(None);
     /// ```
     // https://llvm.org/docs/LangRef.html#atomicrmw-instruction
@@ -2797,6 +3328,15 @@ impl<'ctx> Builder<'ctx> {
     }
 
     /// Builds a cmpxchg instruction. It allows you to atomically compare and replace memory.
+    /// The returned value is a `{ ty, i1 }` struct holding the value previously stored and
+    /// whether or not it was replaced, which can be read with `build_extract_value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `cmp` and `new` differ in type or are neither integers nor pointers,
+    /// if `ptr` does not point to their type, or if the orderings are invalid: both must be
+    /// at least `Monotonic`, `failure` may not be stronger than `success` and may not be
+    /// `Release` or `AcquireRelease`.
     ///
     /// # Example
     ///
@@ -2936,6 +3476,25 @@ fn is_alignment_ok(align: u32) -> bool {
     align > 0 && align.is_power_of_two() && (align as f64).log2() < 64.0
 }
 
+fn assert_call_arg_count(function_type: FunctionType, arg_count: usize) {
+    let param_count = function_type.count_param_types() as usize;
+
+    if function_type.is_var_arg() {
+        assert!(
+            arg_count >= param_count,
+            "Expected at least {} arguments for a variadic call, found {}",
+            param_count,
+            arg_count
+        );
+    } else {
+        assert_eq!(
+            arg_count, param_count,
+            "Expected {} arguments for call, found {}",
+            param_count, arg_count
+        );
+    }
+}
+
 impl Drop for Builder<'_> {
     fn drop(&mut self) {
         unsafe {
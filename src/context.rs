@@ -199,6 +199,11 @@ impl ContextImpl {
     }
 
     fn custom_width_int_type<'ctx>(&self, bits: u32) -> IntType<'ctx> {
+        assert!(
+            (1..=1 << 23).contains(&bits),
+            "Integer bit width must be between 1 and 2^23 inclusive"
+        );
+
         unsafe { IntType::new(LLVMIntTypeInContext(self.0, bits)) }
     }
 
@@ -399,6 +404,9 @@ impl PartialEq<ContextRef<'_>> for Context {
 ///
 /// A `Context` is not thread safe and cannot be shared across threads. Multiple `Context`s
 /// can, however, execute on different threads simultaneously according to the LLVM docs.
+///
+/// A `Context` owns its underlying LLVM context and disposes of it when dropped. Everything
+/// created from it borrows it, so it must outlive its `Module`s, types and values.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Context {
     pub(crate) context: ContextImpl,
@@ -467,6 +475,8 @@ impl Context {
 
     /// Creates a new `Module` for a `Context`.
     ///
+    /// The `Module` borrows the `Context`, so the `Context` cannot be dropped while it is alive.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -474,6 +484,21 @@ impl Context {
     ///
     /// let context = Context::create();
     /// let module = context.create_module("my_module");
+    ///
+    /// assert_eq!(module.get_context(), context);
+    /// ```
+    ///
+    /// Dropping the `Context` first is rejected at compile time:
+    ///
+    /// ```compile_fail,E0505
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// drop(context);
+    ///
+    /// module.get_name();
     /// ```
     #[inline]
     pub fn create_module(&self, name: &str) -> Module {
@@ -502,10 +527,13 @@ impl Context {
     ///
     /// let module2 = context.create_module_from_ir(memory_buffer).unwrap();
     /// ```
-    // REVIEW: I haven't yet been able to find docs or other wrappers that confirm, but my suspicion
-    // is that the method needs to take ownership of the MemoryBuffer... otherwise I see what looks like
-    // a double free in valgrind when the MemoryBuffer drops so we are `forget`ting MemoryBuffer here
-    // for now until we can confirm this is the correct thing to do
+    ///
+    /// The `MemoryBuffer` may contain either bitcode or textual IR. LLVM takes ownership
+    /// of it, which is why it is consumed here.
+    ///
+    /// Textual IR must be followed by a NUL byte in memory. Buffers from
+    /// `MemoryBuffer::create_from_file` and `MemoryBuffer::create_from_memory_range_copy`
+    /// guarantee this, whereas `MemoryBuffer::create_from_memory_range` does not.
     #[inline]
     pub fn create_module_from_ir(&self, memory_buffer: MemoryBuffer) -> Result<Module, LLVMString> {
         self.context.create_module_from_ir(memory_buffer)
@@ -748,6 +776,10 @@ impl Context {
 
     /// Gets the `IntType` representing a custom bit width. It will be assigned the current context.
     ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is zero or greater than 2^23, the widest integer LLVM supports.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -1245,7 +1277,8 @@ impl Context {
         self.context.create_type_attribute(kind_id, type_ref)
     }
 
-    /// Creates a const string which may be null terminated.
+    /// Creates a const string which may be null terminated. The result is an `[N x i8]` array,
+    /// where `N` is the length of `string` in bytes plus one if a null terminator is appended.
     ///
     /// # Example
     ///
@@ -1284,6 +1317,7 @@ impl Drop for Context {
 }
 
 /// A `ContextRef` is a smart pointer allowing borrowed access to a type's `Context`.
+/// Unlike a `Context`, it never disposes of the underlying LLVM context when dropped.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ContextRef<'ctx> {
     pub(crate) context: ContextImpl,
@@ -1350,10 +1384,13 @@ impl<'ctx> ContextRef<'ctx> {
     ///
     /// let module2 = context.create_module_from_ir(memory_buffer).unwrap();
     /// ```
-    // REVIEW: I haven't yet been able to find docs or other wrappers that confirm, but my suspicion
-    // is that the method needs to take ownership of the MemoryBuffer... otherwise I see what looks like
-    // a double free in valgrind when the MemoryBuffer drops so we are `forget`ting MemoryBuffer here
-    // for now until we can confirm this is the correct thing to do
+    ///
+    /// The `MemoryBuffer` may contain either bitcode or textual IR. LLVM takes ownership
+    /// of it, which is why it is consumed here.
+    ///
+    /// Textual IR must be followed by a NUL byte in memory. Buffers from
+    /// `MemoryBuffer::create_from_file` and `MemoryBuffer::create_from_memory_range_copy`
+    /// guarantee this, whereas `MemoryBuffer::create_from_memory_range` does not.
     #[inline]
     pub fn create_module_from_ir(&self, memory_buffer: MemoryBuffer) -> Result<Module<'ctx>, LLVMString> {
         self.context.create_module_from_ir(memory_buffer)
@@ -1596,6 +1633,10 @@ impl<'ctx> ContextRef<'ctx> {
 
     /// Gets the `IntType` representing a custom bit width. It will be assigned the current context.
     ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is zero or greater than 2^23, the widest integer LLVM supports.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -2082,7 +2123,8 @@ impl<'ctx> ContextRef<'ctx> {
         self.context.create_type_attribute(kind_id, type_ref)
     }
 
-    /// Creates a const string which may be null terminated.
+    /// Creates a const string which may be null terminated. The result is an `[N x i8]` array,
+    /// where `N` is the length of `string` in bytes plus one if a null terminator is appended.
     ///
     /// # Example
     ///
@@ -1,8 +1,11 @@
 use std::ffi::CStr;
 use std::fmt;
 
-use crate::support::{LLVMString, LLVMStringOrRaw};
+use crate::support::{to_c_str, LLVMString, LLVMStringOrRaw};
 
+/// The string form of a target's data layout, which describes how types are laid out in memory.
+///
+/// To query sizes and alignments described by a data layout, use `TargetData` instead.
 #[derive(Eq)]
 pub struct DataLayout {
     pub(crate) data_layout: LLVMStringOrRaw,
@@ -25,10 +28,37 @@ impl DataLayout {
         }
     }
 
+    /// Creates a `DataLayout` from its string form, for use with `Module::set_data_layout`.
+    /// The string is not validated.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::data_layout::DataLayout;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let data_layout = DataLayout::create("e-m:e-i64:64-f80:128-n8:16:32:64-S128");
+    ///
+    /// module.set_data_layout(&data_layout);
+    ///
+    /// assert_eq!(*module.get_data_layout(), data_layout);
+    /// ```
+    pub fn create(data_layout: &str) -> DataLayout {
+        let c_string = to_c_str(data_layout);
+
+        DataLayout {
+            data_layout: LLVMStringOrRaw::Owned(LLVMString::create_from_c_str(&c_string)),
+        }
+    }
+
+    /// Gets the string form of this `DataLayout`.
     pub fn as_str(&self) -> &CStr {
         self.data_layout.as_str()
     }
 
+    /// Acquires the underlying raw pointer to the string form of this `DataLayout`.
     pub fn as_ptr(&self) -> *const ::libc::c_char {
         match self.data_layout {
             LLVMStringOrRaw::Owned(ref llvm_string) => llvm_string.ptr,
@@ -122,14 +122,16 @@ impl<'ctx> ExecutionEngine<'ctx> {
         **self.execution_engine_rc()
     }
 
-    /// This function probably doesn't need to be called, but is here due to
-    /// linking(?) requirements. Bad things happen if we don't provide it.
+    /// Forces MCJIT to be linked into the final binary. This is only needed when LLVM
+    /// is linked statically and the linker would otherwise drop it, in which case
+    /// `Module::create_jit_execution_engine` fails to find a JIT.
     pub fn link_in_mc_jit() {
         unsafe { LLVMLinkInMCJIT() }
     }
 
-    /// This function probably doesn't need to be called, but is here due to
-    /// linking(?) requirements. Bad things happen if we don't provide it.
+    /// Forces the interpreter to be linked into the final binary. This is only needed when
+    /// LLVM is linked statically and the linker would otherwise drop it, in which case
+    /// `Module::create_interpreter_execution_engine` reports that it has not been linked in.
     pub fn link_in_interpreter() {
         unsafe {
             LLVMLinkInInterpreter();
@@ -138,6 +140,11 @@ impl<'ctx> ExecutionEngine<'ctx> {
 
     /// Maps the specified value to an address.
     ///
+    /// This lets JIT compiled code refer to host functions or data: the value is usually
+    /// the declaration of a `FunctionValue` or an external `GlobalValue`, and the address a
+    /// matching `extern "C"` function or variable. Mappings must be added before the code
+    /// referring to them is compiled, ie before calling `get_function`.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::targets::{InitializationConfig, Target};
@@ -199,17 +206,38 @@ impl<'ctx> ExecutionEngine<'ctx> {
     /// assert!(ee.add_module(&module).is_err());
     /// ```
     pub fn add_module(&self, module: &Module<'ctx>) -> Result<(), ()> {
-        unsafe { LLVMAddModule(self.execution_engine_inner(), module.module.get()) }
-
         if module.owned_by_ee.borrow().is_some() {
             return Err(());
         }
 
+        unsafe { LLVMAddModule(self.execution_engine_inner(), module.module.get()) }
+
         *module.owned_by_ee.borrow_mut() = Some(self.clone());
 
         Ok(())
     }
 
+    /// Removes a module from an `ExecutionEngine`, handing ownership of it back to the `Module`
+    /// so that it will be disposed of when dropped.
+    ///
+    /// The method will be `Err` if the module isn't owned by this `ExecutionEngine`.
+    ///
+    /// ```rust,no_run
+    /// use inkwell::targets::{InitializationConfig, Target};
+    /// use inkwell::context::Context;
+    /// use inkwell::OptimizationLevel;
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("test");
+    /// let module2 = context.create_module("test2");
+    /// let ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    ///
+    /// assert!(ee.remove_module(&module2).is_err());
+    /// assert!(ee.add_module(&module2).is_ok());
+    /// assert!(ee.remove_module(&module2).is_ok());
+    /// ```
     pub fn remove_module(&self, module: &Module<'ctx>) -> Result<(), RemoveModuleError> {
         match *module.owned_by_ee.borrow() {
             Some(ref ee) if ee.execution_engine_inner() != self.execution_engine_inner() => {
@@ -380,8 +408,37 @@ impl<'ctx> ExecutionEngine<'ctx> {
         Err(FunctionLookupError::FunctionNotFound)
     }
 
-    // TODOC: Marked as unsafe because input function could very well do something unsafe. It's up to the caller
-    // to ensure that doesn't happen by defining their function correctly.
+    /// Runs a function with the given `GenericValue` arguments, returning its result as
+    /// a `GenericValue`. Unlike `get_function`, the signature of the function does not need
+    /// to be known at compile time, which also makes this work with interpreter `ExecutionEngine`s.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("test");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let f = module.add_function("identity", fn_type, None);
+    /// let b = context.append_basic_block(f, "entry");
+    ///
+    /// builder.position_at_end(b);
+    /// builder.build_return(Some(&f.get_first_param().unwrap()));
+    ///
+    /// let ee = module.create_interpreter_execution_engine().unwrap();
+    /// let arg = i32_type.create_generic_value(42, false);
+    /// let result = unsafe { ee.run_function(f, &[&arg]) };
+    ///
+    /// assert_eq!(result.as_int(false), 42);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The function could very well do something unsafe. It's up to the caller to ensure
+    /// that doesn't happen by defining their function correctly and passing matching arguments.
     pub unsafe fn run_function(
         &self,
         function: FunctionValue<'ctx>,
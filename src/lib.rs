@@ -360,16 +360,21 @@ pub enum AtomicRMWBinOp {
     FMin,
 }
 
-/// Defines the optimization level used to compile a `Module`.
+/// Defines the optimization level used to compile a `Module`, both by a JIT `ExecutionEngine`
+/// and by a `TargetMachine`. The discriminants are the numeric levels LLVM expects.
 ///
 /// # Remarks
 /// See also: https://llvm.org/doxygen/CodeGen_8h_source.html
 #[repr(u32)]
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum OptimizationLevel {
+    /// No optimization, equivalent to `-O0`.
     None = 0,
+    /// Equivalent to `-O1`.
     Less = 1,
+    /// Equivalent to `-O2`.
     Default = 2,
+    /// Equivalent to `-O3`.
     Aggressive = 3,
 }
 
@@ -380,13 +385,17 @@ impl Default for OptimizationLevel {
     }
 }
 
+/// Defines whether a symbol is visible outside of the shared library or executable defining it.
 #[llvm_enum(LLVMVisibility)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum GlobalVisibility {
+    /// The symbol is visible to other components and may be overridden by them.
     #[llvm_variant(LLVMDefaultVisibility)]
     Default,
+    /// The symbol is not exported from the component defining it.
     #[llvm_variant(LLVMHiddenVisibility)]
     Hidden,
+    /// The symbol is visible to other components, but can't be overridden by them.
     #[llvm_variant(LLVMProtectedVisibility)]
     Protected,
 }
@@ -398,11 +407,16 @@ impl Default for GlobalVisibility {
     }
 }
 
+/// Defines the thread local storage model used to access a thread local `GlobalValue`.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ThreadLocalMode {
+    /// The most general model, which works everywhere, including from dynamically loaded libraries.
     GeneralDynamicTLSModel,
+    /// An optimization for variables only used within the current shared library.
     LocalDynamicTLSModel,
+    /// An optimization for variables in modules that will not be dynamically loaded.
     InitialExecTLSModel,
+    /// An optimization for variables defined in the executable and only used within it.
     LocalExecTLSModel,
 }
 
@@ -428,13 +442,17 @@ impl ThreadLocalMode {
     }
 }
 
+/// Defines how a symbol is imported from or exported to a DLL on Windows targets.
 #[llvm_enum(LLVMDLLStorageClass)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum DLLStorageClass {
+    /// The symbol is neither imported from nor exported to a DLL.
     #[llvm_variant(LLVMDefaultStorageClass)]
     Default,
+    /// The symbol is imported from a DLL, via `__declspec(dllimport)`.
     #[llvm_variant(LLVMDLLImportStorageClass)]
     Import,
+    /// The symbol is exported from the DLL defining it, via `__declspec(dllexport)`.
     #[llvm_variant(LLVMDLLExportStorageClass)]
     Export,
 }
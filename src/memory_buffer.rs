@@ -30,6 +30,19 @@ impl MemoryBuffer {
         self.memory_buffer
     }
 
+    /// Creates a new `MemoryBuffer` with the contents of the file at `path`,
+    /// returning a LLVM allocated string describing the failure if it could not be read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    /// use std::path::Path;
+    ///
+    /// let buffer = MemoryBuffer::create_from_file(Path::new("foo/bar.bc")).unwrap();
+    ///
+    /// assert!(buffer.get_size() > 0);
+    /// ```
     pub fn create_from_file(path: &Path) -> Result<Self, LLVMString> {
         let path = to_c_str(path.to_str().expect("Did not find a valid Unicode path string"));
         let mut memory_buffer = ptr::null_mut();
@@ -43,7 +56,6 @@ impl MemoryBuffer {
             )
         };
 
-        // TODO: Verify 1 is error code (LLVM can be inconsistent)
         if return_code == 1 {
             unsafe {
                 return Err(LLVMString::new(err_string.assume_init()));
@@ -53,13 +65,13 @@ impl MemoryBuffer {
         unsafe { Ok(MemoryBuffer::new(memory_buffer)) }
     }
 
+    /// Creates a new `MemoryBuffer` with the contents read from stdin.
     pub fn create_from_stdin() -> Result<Self, LLVMString> {
         let mut memory_buffer = ptr::null_mut();
         let mut err_string = MaybeUninit::uninit();
 
         let return_code = unsafe { LLVMCreateMemoryBufferWithSTDIN(&mut memory_buffer, err_string.as_mut_ptr()) };
 
-        // TODO: Verify 1 is error code (LLVM can be inconsistent)
         if return_code == 1 {
             unsafe {
                 return Err(LLVMString::new(err_string.assume_init()));
@@ -72,6 +84,9 @@ impl MemoryBuffer {
     /// This function is likely slightly cheaper than `create_from_memory_range_copy` since it intentionally
     /// leaks data to LLVM so that it doesn't have to reallocate. `create_from_memory_range_copy` may be removed
     /// in the future
+    ///
+    /// The resulting buffer is not guaranteed to be NUL-terminated, so it should not be used to parse
+    /// textual IR. Use `create_from_memory_range_copy` for that instead.
     pub fn create_from_memory_range(input: &[u8], name: &str) -> Self {
         let name_c_string = to_c_str(name);
 
@@ -87,7 +102,8 @@ impl MemoryBuffer {
         unsafe { MemoryBuffer::new(memory_buffer) }
     }
 
-    /// This will create a new `MemoryBuffer` from the given input.
+    /// This will create a new `MemoryBuffer` from the given input. LLVM NUL-terminates the copy,
+    /// making it suitable for parsing textual IR.
     ///
     /// This function is likely slightly more expensive than `create_from_memory_range` since it does not leak
     /// data to LLVM, forcing LLVM to make a copy. This function may be removed in the future in favor of
@@ -107,6 +123,18 @@ impl MemoryBuffer {
     }
 
     /// Gets a byte slice of this `MemoryBuffer`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let buffer = module.write_bitcode_to_memory();
+    ///
+    /// assert_eq!(&buffer.as_slice()[..4], b"BC\xC0\xDE");
+    /// ```
     pub fn as_slice(&self) -> &[u8] {
         unsafe {
             let start = LLVMGetBufferStart(self.memory_buffer);
@@ -48,9 +48,7 @@ use crate::memory_buffer::MemoryBuffer;
 #[llvm_versions(13.0..=latest)]
 use crate::passes::PassBuilderOptions;
 use crate::support::{to_c_str, LLVMString};
-#[llvm_versions(13.0..=latest)]
-use crate::targets::TargetMachine;
-use crate::targets::{InitializationConfig, Target, TargetTriple};
+use crate::targets::{InitializationConfig, Target, TargetMachine, TargetTriple};
 use crate::types::{AsTypeRef, BasicType, FunctionType, StructType};
 #[llvm_versions(7.0..=latest)]
 use crate::values::BasicValue;
@@ -66,6 +64,11 @@ use crate::{AddressSpace, OptimizationLevel};
 ///
 /// All Global Variables, Functions and Aliases can have one of the following DLL storage class: `DLLImport`
 /// & `DLLExport`.
+///
+/// `DLLExport`, `DLLImport`, `Ghost`, `LinkerPrivate`, `LinkerPrivateWeak` and `LinkOnceODRAutoHide` are
+/// obsolete linkages which LLVM only keeps in its C API for compatibility. They are never returned by
+/// `get_linkage`, and setting them either maps to a replacement or leaves the linkage unchanged, as
+/// described on each variant.
 // REVIEW: Maybe this should go into it's own module?
 pub enum Linkage {
     /// `Appending` linkage may only be applied to global variables of pointer to array type. When two global
@@ -95,11 +98,17 @@ pub enum Linkage {
     /// combining __imp_ and the function or variable name. Since this storage class exists for defining a dll
     /// interface, the compiler, assembler and linker know it is externally referenced and must refrain from
     /// deleting the symbol.
+    ///
+    /// Obsolete: setting this linkage leaves the current one unchanged. Use
+    /// `GlobalValue::set_dll_storage_class` with `DLLStorageClass::Export` instead.
     #[llvm_variant(LLVMDLLExportLinkage)]
     DLLExport,
     /// `DLLImport` causes the compiler to reference a function or variable via a global pointer to a pointer
     /// that is set up by the DLL exporting the symbol. On Microsoft Windows targets, the pointer name is
     /// formed by combining __imp_ and the function or variable name.
+    ///
+    /// Obsolete: setting this linkage leaves the current one unchanged. Use
+    /// `GlobalValue::set_dll_storage_class` with `DLLStorageClass::Import` instead.
     #[llvm_variant(LLVMDLLImportLinkage)]
     DLLImport,
     /// If none of the other identifiers are used, the global is externally visible, meaning that it
@@ -110,17 +119,17 @@ pub enum Linkage {
     /// if not linked, the symbol becomes null instead of being an undefined reference.
     #[llvm_variant(LLVMExternalWeakLinkage)]
     ExternalWeak,
-    /// FIXME: Unknown linkage type
+    /// Obsolete linkage with no replacement. Setting it leaves the current linkage unchanged.
     #[llvm_variant(LLVMGhostLinkage)]
     Ghost,
     /// Similar to private, but the value shows as a local symbol (STB_LOCAL in the case of ELF) in the object
     /// file. This corresponds to the notion of the 'static' keyword in C.
     #[llvm_variant(LLVMInternalLinkage)]
     Internal,
-    /// FIXME: Unknown linkage type
+    /// Obsolete: setting this linkage sets `Private` instead.
     #[llvm_variant(LLVMLinkerPrivateLinkage)]
     LinkerPrivate,
-    /// FIXME: Unknown linkage type
+    /// Obsolete: setting this linkage sets `Private` instead.
     #[llvm_variant(LLVMLinkerPrivateWeakLinkage)]
     LinkerPrivateWeak,
     /// Globals with `LinkOnceAny` linkage are merged with other globals of the same name when linkage occurs.
@@ -133,7 +142,8 @@ pub enum Linkage {
     /// optimizations, use `LinkOnceODR` linkage.
     #[llvm_variant(LLVMLinkOnceAnyLinkage)]
     LinkOnceAny,
-    /// FIXME: Unknown linkage type
+    /// Obsolete: setting this linkage leaves the current one unchanged. Use `LinkOnceODR` together
+    /// with `GlobalValue::set_unnamed_addr` instead.
     #[llvm_variant(LLVMLinkOnceODRAutoHideLinkage)]
     LinkOnceODRAutoHide,
     /// Some languages allow differing globals to be merged, such as two functions with different semantics.
@@ -224,6 +234,42 @@ impl<'ctx> Module<'ctx> {
         fn_value
     }
 
+    /// Gets a function with the given `name` from this `Module`, or creates and adds it
+    /// with the given `ty` and `linkage` if it doesn't exist yet. This avoids accidentally
+    /// creating duplicate declarations, as calling `add_function` twice would.
+    ///
+    /// An `Err` is returned if a function with this name already exists but has a type
+    /// other than `ty`. The `linkage` is only applied to a newly created function.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// let fn_type = context.f32_type().fn_type(&[], false);
+    /// let fn_val = module.get_or_insert_function("my_function", fn_type, None).unwrap();
+    ///
+    /// assert_eq!(module.get_or_insert_function("my_function", fn_type, None), Ok(fn_val));
+    ///
+    /// let other_fn_type = context.i32_type().fn_type(&[], false);
+    ///
+    /// assert!(module.get_or_insert_function("my_function", other_fn_type, None).is_err());
+    /// ```
+    pub fn get_or_insert_function(
+        &self,
+        name: &str,
+        ty: FunctionType<'ctx>,
+        linkage: Option<Linkage>,
+    ) -> Result<FunctionValue<'ctx>, &'static str> {
+        match self.get_function(name) {
+            Some(fn_value) if fn_value.get_type() == ty => Ok(fn_value),
+            Some(_) => Err("A function with this name but of a different type already exists."),
+            None => Ok(self.add_function(name, ty, linkage)),
+        }
+    }
+
     /// Gets the `Context` from which this `Module` originates.
     ///
     /// # Example
@@ -435,7 +481,44 @@ impl<'ctx> Module<'ctx> {
         unsafe { TargetTriple::new(LLVMString::create_from_c_str(CStr::from_ptr(target_str))) }
     }
 
-    /// Creates an `ExecutionEngine` from this `Module`.
+    /// Assigns both the `TargetTriple` and the `DataLayout` of a `TargetMachine` to this `Module`,
+    /// keeping the two consistent with each other.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::OptimizationLevel;
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetMachine};
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+    ///
+    /// let triple = TargetMachine::get_default_triple();
+    /// let target = Target::from_triple(&triple).unwrap();
+    /// let target_machine = target
+    ///     .create_target_machine(&triple, "", "", OptimizationLevel::None, RelocMode::Default, CodeModel::Default)
+    ///     .unwrap();
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// module.set_triple_from_machine(&target_machine);
+    ///
+    /// assert_eq!(module.get_triple(), target_machine.get_triple());
+    /// assert_eq!(*module.get_data_layout(), target_machine.get_target_data().get_data_layout());
+    /// ```
+    pub fn set_triple_from_machine(&self, machine: &TargetMachine) {
+        self.set_triple(&machine.get_triple());
+        self.set_data_layout(&machine.get_target_data().get_data_layout());
+    }
+
+    /// Creates an `ExecutionEngine` from this `Module`. LLVM picks a JIT if one is
+    /// available for the host and falls back to the interpreter otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the native target could not be initialized, if this `Module`
+    /// is already owned by another `ExecutionEngine` or if LLVM fails to create one.
     ///
     /// # Example
     /// ```no_run
@@ -489,7 +572,13 @@ impl<'ctx> Module<'ctx> {
         Ok(execution_engine)
     }
 
-    /// Creates an interpreter `ExecutionEngine` from this `Module`.
+    /// Creates an interpreter `ExecutionEngine` from this `Module`. Functions are run
+    /// through `ExecutionEngine::run_function` rather than being compiled to machine code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the native target could not be initialized, if this `Module`
+    /// is already owned by another `ExecutionEngine` or if LLVM fails to create one.
     ///
     /// # Example
     /// ```no_run
@@ -544,7 +633,14 @@ impl<'ctx> Module<'ctx> {
         Ok(execution_engine)
     }
 
-    /// Creates a JIT `ExecutionEngine` from this `Module`.
+    /// Creates a JIT `ExecutionEngine` from this `Module`, which compiles functions to
+    /// machine code at the given `OptimizationLevel` so they can be called through
+    /// `ExecutionEngine::get_function`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the native target could not be initialized, if this `Module`
+    /// is already owned by another `ExecutionEngine` or if LLVM fails to create one.
     ///
     /// # Example
     /// ```no_run
@@ -669,16 +765,39 @@ impl<'ctx> Module<'ctx> {
         unsafe { LLVMWriteBitcodeToFile(self.module.get(), c_string.as_ptr()) == 0 }
     }
 
-    // See GH issue #6
-    /// `write_bitcode_to_path` should be preferred over this method, as it does not work on all operating systems.
+    /// Writes a `Module` to an open `File` handle.
+    ///
+    /// `write_bitcode_to_path` should be preferred over this method. On unix platforms the
+    /// bitcode is written straight to the file's descriptor, elsewhere it is first written to a
+    /// `MemoryBuffer` and then copied into the file, in which case `should_close` and
+    /// `unbuffered` have no effect.
+    ///
+    /// `should_close` tells LLVM to close the file descriptor once done. Since the descriptor
+    /// is still owned by `file`, which will also close it on drop, this should almost always
+    /// be `false`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// use std::fs::File;
+    ///
+    /// let file = File::create("module.bc").unwrap();
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    ///
+    /// module.add_function("my_fn", fn_type, None);
+    /// module.write_bitcode_to_file(&file, false, false);
+    /// ```
     pub fn write_bitcode_to_file(&self, file: &File, should_close: bool, unbuffered: bool) -> bool {
         #[cfg(unix)]
         {
             use llvm_sys::bit_writer::LLVMWriteBitcodeToFD;
             use std::os::unix::io::AsRawFd;
 
-            // REVIEW: as_raw_fd docs suggest it only works in *nix
-            // Also, should_close should maybe be hardcoded to true?
             unsafe {
                 LLVMWriteBitcodeToFD(
                     self.module.get(),
@@ -689,7 +808,14 @@ impl<'ctx> Module<'ctx> {
             }
         }
         #[cfg(not(unix))]
-        return false;
+        {
+            use std::io::Write;
+
+            let _ = (should_close, unbuffered);
+            let mut file = file;
+
+            file.write_all(self.write_bitcode_to_memory().as_slice()).is_ok()
+        }
     }
 
     /// Writes this `Module` to a `MemoryBuffer`.
@@ -730,9 +856,13 @@ impl<'ctx> Module<'ctx> {
 
         let code = unsafe { LLVMVerifyModule(self.module.get(), action, err_str.as_mut_ptr()) };
 
+        // LLVM allocates the message even when verification succeeds, so it must
+        // always be taken ownership of in order to be disposed.
         let err_str = unsafe { err_str.assume_init() };
-        if code == 1 && !err_str.is_null() {
-            return unsafe { Err(LLVMString::new(err_str)) };
+        let err_str = (!err_str.is_null()).then(|| unsafe { LLVMString::new(err_str) });
+
+        if code == 1 {
+            return Err(err_str.unwrap_or_else(|| LLVMString::create_from_str("Module verification failed\0")));
         }
 
         Ok(())
@@ -818,7 +948,19 @@ impl<'ctx> Module<'ctx> {
         unsafe { LLVMString::new(LLVMPrintModuleToString(self.module.get())) }
     }
 
-    /// Prints the content of the `Module` to a file.
+    /// Prints the content of the `Module` to a file, returning a LLVM allocated
+    /// string describing the failure if it could not be written.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// module.print_to_file("my_module.ll").expect("Could not write IR");
+    /// ```
     pub fn print_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), LLVMString> {
         let path_str = path
             .as_ref()
@@ -866,11 +1008,38 @@ impl<'ctx> Module<'ctx> {
         }
     }
 
+    /// Appends to the inline assembly of the `Module`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// module.set_inline_assembly(".globl foo");
+    /// module.append_inline_assembly(".globl bar");
+    ///
+    /// assert!(module.to_string().contains("module asm \".globl bar\""));
+    /// ```
+    #[llvm_versions(7.0..=latest)]
+    pub fn append_inline_assembly(&self, asm: &str) {
+        use llvm_sys::core::LLVMAppendModuleInlineAsm;
+
+        unsafe { LLVMAppendModuleInlineAsm(self.module.get(), asm.as_ptr() as *const ::libc::c_char, asm.len()) }
+    }
+
     // REVIEW: Should module take ownership of metadata?
     // REVIEW: Should we return a MetadataValue for the global since it's its own value?
     // it would be the last item in get_global_metadata I believe
-    // TODOC: Appends your metadata to a global MetadataValue<Node> indexed by key
     /// Appends a `MetaDataValue` to a global list indexed by a particular key.
+    /// This is an operand of the named metadata `!key`, such as `!llvm.ident`.
+    ///
+    /// # Errors
+    ///
+    /// Only metadata nodes may be operands of named metadata, so passing a metadata
+    /// string directly will return an `Err`. Wrap it in a `Context::metadata_node` instead.
     ///
     /// # Example
     ///
@@ -887,9 +1056,10 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(module.get_global_metadata_size("my_md"), 0);
     ///
     /// let md_string = context.metadata_string("lots of metadata here");
+    /// let md_string_node = context.metadata_node(&[md_string.into()]);
     /// let md_node = context.metadata_node(&[bool_val.into(), f32_val.into()]);
     ///
-    /// module.add_global_metadata("my_md", &md_string).unwrap();
+    /// module.add_global_metadata("my_md", &md_string_node).unwrap();
     /// module.add_global_metadata("my_md", &md_node).unwrap();
     ///
     /// assert_eq!(module.get_global_metadata_size("my_md"), 2);
@@ -937,9 +1107,10 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(module.get_global_metadata_size("my_md"), 0);
     ///
     /// let md_string = context.metadata_string("lots of metadata here");
+    /// let md_string_node = context.metadata_node(&[md_string.into()]);
     /// let md_node = context.metadata_node(&[bool_val.into(), f32_val.into()]);
     ///
-    /// module.add_global_metadata("my_md", &md_string).unwrap();
+    /// module.add_global_metadata("my_md", &md_string_node).unwrap();
     /// module.add_global_metadata("my_md", &md_node).unwrap();
     ///
     /// assert_eq!(module.get_global_metadata_size("my_md"), 2);
@@ -980,9 +1151,10 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(module.get_global_metadata_size("my_md"), 0);
     ///
     /// let md_string = context.metadata_string("lots of metadata here");
+    /// let md_string_node = context.metadata_node(&[md_string.into()]);
     /// let md_node = context.metadata_node(&[bool_val.into(), f32_val.into()]);
     ///
-    /// module.add_global_metadata("my_md", &md_string).unwrap();
+    /// module.add_global_metadata("my_md", &md_string_node).unwrap();
     /// module.add_global_metadata("my_md", &md_node).unwrap();
     ///
     /// assert_eq!(module.get_global_metadata_size("my_md"), 2);
@@ -1101,6 +1273,22 @@ impl<'ctx> Module<'ctx> {
     }
 
     /// An iterator over the globals in this `Module`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let i8_type = context.i8_type();
+    ///
+    /// assert!(module.get_globals().next().is_none());
+    ///
+    /// let global = module.add_global(i8_type, None, "my_global");
+    ///
+    /// assert_eq!(module.get_globals().collect::<Vec<_>>(), vec![global]);
+    /// ```
     pub fn get_globals(&self) -> GlobalIterator<'ctx> {
         GlobalIterator::from_module(self)
     }
@@ -1189,7 +1377,7 @@ impl<'ctx> Module<'ctx> {
     /// let context = Context::create();
     /// let module = context.create_module("my_module");
     ///
-    /// assert_eq!(module.get_name().to_str(), Ok("my_mdoule"));
+    /// assert_eq!(module.get_name().to_str(), Ok("my_module"));
     /// ```
     pub fn get_name(&self) -> &CStr {
         let mut length = 0;
@@ -1351,6 +1539,23 @@ impl<'ctx> Module<'ctx> {
 
     /// Append a `MetadataValue` as a module wide flag. Note that using the same key twice
     /// will likely invalidate the module.
+    ///
+    /// Module flags end up in the `!llvm.module.flags` named metadata of the module.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::FlagBehavior;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let md_string = context.metadata_string("my value");
+    ///
+    /// module.add_metadata_flag("my_flag", FlagBehavior::Warning, md_string);
+    ///
+    /// assert!(module.get_flag("my_flag").is_some());
+    /// ```
     #[llvm_versions(7.0..=latest)]
     pub fn add_metadata_flag(&self, key: &str, behavior: FlagBehavior, flag: MetadataValue<'ctx>) {
         let md = flag.as_metadata_ref();
@@ -1368,6 +1573,21 @@ impl<'ctx> Module<'ctx> {
 
     /// Append a `BasicValue` as a module wide flag. Note that using the same key twice
     /// will likely invalidate the module.
+    ///
+    /// This is the usual way to set integer flags such as `"Debug Info Version"`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::FlagBehavior;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let version = context.i32_type().const_int(inkwell::debug_info::debug_metadata_version() as u64, false);
+    ///
+    /// module.add_basic_value_flag("Debug Info Version", FlagBehavior::Warning, version);
+    /// ```
     // REVIEW: What happens if value is not const?
     #[llvm_versions(7.0..=latest)]
     pub fn add_basic_value_flag<BV: BasicValue<'ctx>>(&self, key: &str, behavior: FlagBehavior, flag: BV) {
@@ -1505,6 +1725,8 @@ impl<'ctx> Module<'ctx> {
 }
 
 impl Clone for Module<'_> {
+    /// Clones this `Module` into a new one in the same `Context`. The clone is not owned by
+    /// any `ExecutionEngine` this module may belong to, and is disposed of when dropped.
     fn clone(&self) -> Self {
         // REVIEW: Is this just a LLVM 6 bug? We could conditionally compile this assertion for affected versions
         let verify = self.verify();
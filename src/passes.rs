@@ -86,17 +86,21 @@ impl PassManagerBuilder {
         self.pass_manager_builder
     }
 
+    /// Creates a new `PassManagerBuilder`, which can be used to populate
+    /// `PassManager`s with a standard optimization pipeline.
     pub fn create() -> Self {
         let pass_manager_builder = unsafe { LLVMPassManagerBuilderCreate() };
 
         unsafe { PassManagerBuilder::new(pass_manager_builder) }
     }
 
+    /// Sets the optimization level to use when populating pass managers, as with `-O0` through `-O3`.
     pub fn set_optimization_level(&self, opt_level: OptimizationLevel) {
         unsafe { LLVMPassManagerBuilderSetOptLevel(self.pass_manager_builder, opt_level as u32) }
     }
 
     // REVIEW: Valid input 0-2 according to llvmlite. Maybe better as an enum?
+    /// Sets the size optimization level, where 1 corresponds to `-Os` and 2 to `-Oz`.
     pub fn set_size_level(&self, size_level: u32) {
         unsafe { LLVMPassManagerBuilderSetSizeLevel(self.pass_manager_builder, size_level) }
     }
@@ -262,11 +266,36 @@ impl PassManager<FunctionValue<'_>> {
         self.pass_manager
     }
 
-    // return true means some pass modified the module, not an error occurred
+    /// Runs the initializers of all of the function passes in this `PassManager`.
+    /// This should be called once, before any function is run with `run_on`.
+    ///
+    /// Returns true if any of the initializers modified the module, not if an error occurred.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::passes::PassManager;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    /// let fn_pass_manager = PassManager::create(&module);
+    ///
+    /// fn_pass_manager.add_cfg_simplification_pass();
+    /// fn_pass_manager.initialize();
+    /// fn_pass_manager.run_on(&fn_value);
+    /// fn_pass_manager.finalize();
+    /// ```
     pub fn initialize(&self) -> bool {
         unsafe { LLVMInitializeFunctionPassManager(self.pass_manager) == 1 }
     }
 
+    /// Runs the finalizers of all of the function passes in this `PassManager`.
+    /// This should be called once, after every function has been run with `run_on`.
+    ///
+    /// Returns true if any of the finalizers modified the module.
     pub fn finalize(&self) -> bool {
         unsafe { LLVMFinalizeFunctionPassManager(self.pass_manager) == 1 }
     }
@@ -282,6 +311,25 @@ impl<T: PassManagerSubType> PassManager<T> {
         }
     }
 
+    /// Creates a new `PassManager`. A `PassManager<Module>` for whole module passes
+    /// is created from `()`, whereas a `PassManager<FunctionValue>` is created from
+    /// the `Module` whose functions it will later be run on.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Module;
+    /// use inkwell::passes::PassManager;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// let module_pass_manager: PassManager<Module> = PassManager::create(());
+    ///
+    /// module_pass_manager.add_global_dce_pass();
+    /// module_pass_manager.run_on(&module);
+    /// ```
     pub fn create<I: Borrow<T::Input>>(input: I) -> PassManager<T> {
         let pass_manager = unsafe { T::create(input) };
 
@@ -775,6 +823,34 @@ impl<T: PassManagerSubType> PassManager<T> {
     /// to place phi nodes, then traversing the function in depth-first
     /// order to rewrite loads and stores as appropriate. This is just
     /// the standard SSA construction algorithm to construct "pruned" SSA form.
+    ///
+    /// # Example
+    ///
+    /// A classic `-O1` style function pipeline which first promotes allocas to registers:
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::passes::PassManager;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let fn_pass_manager = PassManager::create(&module);
+    ///
+    /// fn_pass_manager.add_basic_alias_analysis_pass();
+    /// fn_pass_manager.add_promote_memory_to_register_pass();
+    /// fn_pass_manager.add_instruction_combining_pass();
+    /// fn_pass_manager.add_reassociate_pass();
+    /// fn_pass_manager.add_gvn_pass();
+    /// fn_pass_manager.add_cfg_simplification_pass();
+    ///
+    /// fn_pass_manager.initialize();
+    ///
+    /// for function in module.get_functions() {
+    ///     fn_pass_manager.run_on(&function);
+    /// }
+    ///
+    /// fn_pass_manager.finalize();
+    /// ```
     pub fn add_promote_memory_to_register_pass(&self) {
         #[llvm_versions(4.0..=6.0)]
         use llvm_sys::transforms::scalar::LLVMAddPromoteMemoryToRegisterPass;
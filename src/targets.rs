@@ -36,21 +36,36 @@ use std::mem::MaybeUninit;
 use std::path::Path;
 use std::ptr;
 
+/// Defines the code model a `TargetMachine` uses, which constrains how far apart code and
+/// data may be placed in memory and so which addressing modes can be used to reach them.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum CodeModel {
+    /// The target's default code model.
     Default,
+    /// The target's default code model for JIT compilation.
     JITDefault,
+    /// Code and data are linked within a small range of addresses, such as the lower 2GB on x86-64.
     Small,
+    /// Like `Small`, but for code linked in the negative address space, such as an OS kernel.
     Kernel,
+    /// Code may be placed anywhere but is still linked in a small range, whereas data may be anywhere.
     Medium,
+    /// No assumptions are made about where code and data are placed.
     Large,
 }
 
+/// Defines the relocation model a `TargetMachine` uses, which determines how code refers
+/// to addresses that are only known once it is linked or loaded.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum RelocMode {
+    /// The target's default relocation model.
     Default,
+    /// Addresses are fixed at link time, as for a non-PIE executable.
     Static,
+    /// Position independent code, as needed for shared libraries. External symbols are
+    /// reached indirectly, such as through the global offset table.
     PIC,
+    /// Code refers to itself directly while external symbols are reached indirectly, as on Darwin.
     DynamicNoPic,
 }
 
@@ -69,14 +84,21 @@ impl FileType {
     }
 }
 
-// TODO: Doc: Base gets you TargetMachine support, machine_code gets you asm_backend
+/// Selects which components of a target are initialized by the `Target::initialize_*` functions.
+/// Everything is initialized by default.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct InitializationConfig {
+    /// Initializes the assembly parser, used to parse inline or module level assembly.
     pub asm_parser: bool,
+    /// Initializes the assembly printer, needed to emit assembly or object files.
     pub asm_printer: bool,
+    /// Initializes the target itself, which gets you `TargetMachine` support.
     pub base: bool,
+    /// Initializes the disassembler.
     pub disassembler: bool,
+    /// Initializes the target info, which allows looking the `Target` up by name or triple.
     pub info: bool,
+    /// Initializes the machine code layer, which gets you an asm backend.
     pub machine_code: bool,
 }
 
@@ -794,6 +816,24 @@ impl Target {
         }
     }
 
+    /// Initializes the target of the host machine. This needs to be called before a
+    /// `TargetMachine` or JIT `ExecutionEngine` can be created for the host.
+    ///
+    /// Unlike the other initialization functions, this reports an error if the host
+    /// target was not built into LLVM. Setting `base` also initializes the target info and
+    /// machine code layer, so the `info` and `machine_code` fields of `config` are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::{InitializationConfig, Target, TargetMachine};
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+    ///
+    /// let host = Target::from_triple(&TargetMachine::get_default_triple()).unwrap();
+    ///
+    /// assert!(host.has_target_machine());
+    /// ```
     pub fn initialize_native(config: &InitializationConfig) -> Result<(), String> {
         use llvm_sys::target::{
             LLVM_InitializeNativeAsmParser, LLVM_InitializeNativeAsmPrinter, LLVM_InitializeNativeDisassembler,
@@ -927,6 +967,24 @@ impl Target {
         unsafe { Some(TargetMachine::new(target_machine)) }
     }
 
+    /// Gets the first `Target` registered with LLVM, or `None` if no targets have been
+    /// initialized. Together with `get_next` this walks every registered target.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::{InitializationConfig, Target};
+    ///
+    /// Target::initialize_all(&InitializationConfig::default());
+    ///
+    /// let mut target = Target::get_first();
+    ///
+    /// while let Some(t) = target {
+    ///     println!("{:?}: {:?}", t.get_name(), t.get_description());
+    ///
+    ///     target = t.get_next();
+    /// }
+    /// ```
     pub fn get_first() -> Option<Self> {
         let target = {
             let _guard = TARGET_LOCK.read();
@@ -940,6 +998,7 @@ impl Target {
         unsafe { Some(Target::new(target)) }
     }
 
+    /// Gets the `Target` registered after this one, or `None` if this is the last.
     pub fn get_next(&self) -> Option<Self> {
         let target = unsafe { LLVMGetNextTarget(self.target) };
 
@@ -950,10 +1009,12 @@ impl Target {
         unsafe { Some(Target::new(target)) }
     }
 
+    /// Gets the short name of this `Target`, such as `x86-64`, as accepted by `Target::from_name`.
     pub fn get_name(&self) -> &CStr {
         unsafe { CStr::from_ptr(LLVMGetTargetName(self.target)) }
     }
 
+    /// Gets a human readable description of this `Target`, such as `64-bit X86: EM64T and AMD64`.
     pub fn get_description(&self) -> &CStr {
         unsafe { CStr::from_ptr(LLVMGetTargetDescription(self.target)) }
     }
@@ -995,14 +1056,17 @@ impl Target {
         unsafe { Ok(Target::new(target)) }
     }
 
+    /// Determines whether this `Target` supports JIT compilation.
     pub fn has_jit(&self) -> bool {
         unsafe { LLVMTargetHasJIT(self.target) == 1 }
     }
 
+    /// Determines whether a `TargetMachine` can be created for this `Target`.
     pub fn has_target_machine(&self) -> bool {
         unsafe { LLVMTargetHasTargetMachine(self.target) == 1 }
     }
 
+    /// Determines whether this `Target` has an assembly backend, which is needed to emit object files.
     pub fn has_asm_backend(&self) -> bool {
         unsafe { LLVMTargetHasAsmBackend(self.target) == 1 }
     }
@@ -1025,10 +1089,38 @@ impl TargetMachine {
         self.target_machine
     }
 
+    /// Gets the `Target` this `TargetMachine` was created from.
     pub fn get_target(&self) -> Target {
         unsafe { Target::new(LLVMGetTargetMachineTarget(self.target_machine)) }
     }
 
+    /// Gets the triple this `TargetMachine` was created with, as given to
+    /// `Target::create_target_machine`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::OptimizationLevel;
+    /// use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetTriple};
+    ///
+    /// Target::initialize_x86(&InitializationConfig::default());
+    ///
+    /// let target = Target::from_name("x86-64").unwrap();
+    /// let target_machine = target
+    ///     .create_target_machine(
+    ///         &TargetTriple::create("x86_64-pc-linux-gnu"),
+    ///         "skylake",
+    ///         "+avx2,-avx512f",
+    ///         OptimizationLevel::Default,
+    ///         RelocMode::Default,
+    ///         CodeModel::Default,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(target_machine.get_triple().as_str().to_str(), Ok("x86_64-pc-linux-gnu"));
+    /// assert_eq!(target_machine.get_cpu().to_str(), Ok("skylake"));
+    /// assert_eq!(target_machine.get_feature_string().to_str(), Ok("+avx2,-avx512f"));
+    /// ```
     pub fn get_triple(&self) -> TargetTriple {
         let str = unsafe { LLVMString::new(LLVMGetTargetMachineTriple(self.target_machine)) };
 
@@ -1052,6 +1144,19 @@ impl TargetMachine {
         unsafe { TargetTriple::new(llvm_string) }
     }
 
+    /// Normalizes a triple into its canonical `arch-vendor-os-environment` form,
+    /// filling in any missing components with "unknown".
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::{TargetMachine, TargetTriple};
+    ///
+    /// let triple = TargetTriple::create("x86_64-linux-gnu");
+    /// let normalized = TargetMachine::normalize_triple(&triple);
+    ///
+    /// assert_eq!(normalized.as_str().to_str(), Ok("x86_64-unknown-linux-gnu"));
+    /// ```
     #[llvm_versions(7.0..=latest)]
     pub fn normalize_triple(triple: &TargetTriple) -> TargetTriple {
         use llvm_sys::target_machine::LLVMNormalizeTargetTriple;
@@ -1061,11 +1166,35 @@ impl TargetMachine {
         unsafe { TargetTriple::new(normalized) }
     }
 
-    /// Gets a string containing the host CPU's name (triple).
+    /// Gets the name of the host CPU, which together with `get_host_cpu_features` lets a
+    /// `TargetMachine` be tuned for the machine it is running on. This is the CPU name,
+    /// not the triple, which is given by `get_default_triple`.
     ///
     /// # Example Output
     ///
-    /// `x86_64-pc-linux-gnu`
+    /// `skylake`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::OptimizationLevel;
+    /// use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetMachine};
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    ///
+    /// let triple = TargetMachine::get_default_triple();
+    /// let target = Target::from_triple(&triple).unwrap();
+    /// let target_machine = target
+    ///     .create_target_machine(
+    ///         &triple,
+    ///         TargetMachine::get_host_cpu_name().to_str().unwrap(),
+    ///         TargetMachine::get_host_cpu_features().to_str().unwrap(),
+    ///         OptimizationLevel::Aggressive,
+    ///         RelocMode::Default,
+    ///         CodeModel::Default,
+    ///     )
+    ///     .unwrap();
+    /// ```
     #[llvm_versions(7.0..=latest)]
     pub fn get_host_cpu_name() -> LLVMString {
         use llvm_sys::target_machine::LLVMGetHostCPUName;
@@ -1073,7 +1202,8 @@ impl TargetMachine {
         unsafe { LLVMString::new(LLVMGetHostCPUName()) }
     }
 
-    /// Gets a comma separated list of supported features by the host CPU.
+    /// Gets a comma separated list of the features supported or not supported by the host CPU,
+    /// in the form expected by `Target::create_target_machine`.
     ///
     /// # Example Output
     ///
@@ -1085,10 +1215,12 @@ impl TargetMachine {
         unsafe { LLVMString::new(LLVMGetHostCPUFeatures()) }
     }
 
+    /// Gets the CPU name this `TargetMachine` was created with.
     pub fn get_cpu(&self) -> LLVMString {
         unsafe { LLVMString::new(LLVMGetTargetMachineCPU(self.target_machine)) }
     }
 
+    /// Gets the feature string this `TargetMachine` was created with, such as `+avx2,-avx512f`.
     pub fn get_feature_string(&self) -> &CStr {
         unsafe { CStr::from_ptr(LLVMGetTargetMachineFeatureString(self.target_machine)) }
     }
@@ -1108,7 +1240,13 @@ impl TargetMachine {
         unsafe { LLVMAddAnalysisPasses(self.target_machine, pass_manager.pass_manager) }
     }
 
-    /// Writes a `TargetMachine` to a `MemoryBuffer`.
+    /// Compiles a `Module` with this `TargetMachine` and writes the resulting assembly or object
+    /// code into a `MemoryBuffer` rather than a file. The bytes are available via
+    /// `MemoryBuffer::as_slice`, or the buffer can be parsed with `MemoryBuffer::create_object_file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns LLVM's error message if the target cannot emit the requested `FileType`.
     ///
     /// # Example
     ///
@@ -1140,7 +1278,9 @@ impl TargetMachine {
     ///
     /// module.add_function("my_fn", fn_type, None);
     ///
-    /// let buffer = target_machine.write_to_memory_buffer(&module, FileType::Assembly).unwrap();
+    /// let buffer = target_machine.write_to_memory_buffer(&module, FileType::Object).unwrap();
+    ///
+    /// assert!(buffer.as_slice().starts_with(b"\x7fELF"));
     /// ```
     pub fn write_to_memory_buffer(&self, module: &Module, file_type: FileType) -> Result<MemoryBuffer, LLVMString> {
         let mut memory_buffer = ptr::null_mut();
@@ -1320,6 +1460,7 @@ impl TargetData {
         }
     }
 
+    /// Gets the size of a pointer in bytes, in the given address space or the default one.
     pub fn get_pointer_byte_size(&self, address_space: Option<AddressSpace>) -> u32 {
         match address_space {
             Some(address_space) => unsafe { LLVMPointerSizeForAS(self.target_data, address_space.0) },
@@ -1327,14 +1468,18 @@ impl TargetData {
         }
     }
 
+    /// Gets the maximum number of bytes that may be overwritten by storing a value of the given type.
     pub fn get_store_size(&self, type_: &dyn AnyType) -> u64 {
         unsafe { LLVMStoreSizeOfType(self.target_data, type_.as_type_ref()) }
     }
 
+    /// Gets the size of the given type in bytes, including the tail padding needed
+    /// to place it in an array.
     pub fn get_abi_size(&self, type_: &dyn AnyType) -> u64 {
         unsafe { LLVMABISizeOfType(self.target_data, type_.as_type_ref()) }
     }
 
+    /// Gets the minimum alignment of the given type in bytes, as required by the target ABI.
     pub fn get_abi_alignment(&self, type_: &dyn AnyType) -> u32 {
         unsafe { LLVMABIAlignmentOfType(self.target_data, type_.as_type_ref()) }
     }
@@ -1351,12 +1496,40 @@ impl TargetData {
         unsafe { LLVMPreferredAlignmentOfGlobal(self.target_data, value.as_value_ref()) }
     }
 
+    /// Gets the index of the struct field which contains the given byte offset.
     pub fn element_at_offset(&self, struct_type: &StructType, offset: u64) -> u32 {
         unsafe { LLVMElementAtOffset(self.target_data, struct_type.as_type_ref(), offset) }
     }
 
+    /// Gets the byte offset of the struct field at the given index, or `None` if the
+    /// struct has no such field. This is useful for laying out structs across FFI.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::OptimizationLevel;
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetMachine};
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    ///
+    /// let triple = TargetMachine::get_default_triple();
+    /// let target = Target::from_triple(&triple).unwrap();
+    /// let target_machine = target
+    ///     .create_target_machine(&triple, "", "", OptimizationLevel::None, RelocMode::Default, CodeModel::Default)
+    ///     .unwrap();
+    /// let target_data = target_machine.get_target_data();
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let i32_type = context.i32_type();
+    /// let struct_type = context.struct_type(&[i8_type.into(), i32_type.into()], false);
+    ///
+    /// assert_eq!(target_data.offset_of_element(&struct_type, 1), Some(4));
+    /// assert_eq!(target_data.offset_of_element(&struct_type, 2), None);
+    /// ```
     pub fn offset_of_element(&self, struct_type: &StructType, element: u32) -> Option<u64> {
-        if element > struct_type.count_fields() - 1 {
+        if element >= struct_type.count_fields() {
             return None;
         }
 
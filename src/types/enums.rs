@@ -516,7 +516,8 @@ impl<'ctx> BasicTypeEnum<'ctx> {
         matches!(self, BasicTypeEnum::VectorType(_))
     }
 
-    /// Creates a constant `BasicValueZero`.
+    /// Creates a constant zero value of this type, which is a `null` pointer for
+    /// `PointerType`s and `zeroinitializer` for aggregates.
     ///
     /// # Example
     /// ```
@@ -538,6 +539,59 @@ impl<'ctx> BasicTypeEnum<'ctx> {
         }
     }
 
+    /// Creates an undefined instance of this type, such as a starting point for
+    /// building up an aggregate with `Builder::build_insert_value`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::BasicType;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let struct_type = context.struct_type(&[i32_type.into(), i32_type.into()], false);
+    /// let struct_undef = struct_type.as_basic_type_enum().get_undef();
+    ///
+    /// assert!(struct_undef.into_struct_value().is_undef());
+    /// ```
+    pub fn get_undef(self) -> BasicValueEnum<'ctx> {
+        match self {
+            BasicTypeEnum::ArrayType(ty) => ty.get_undef().as_basic_value_enum(),
+            BasicTypeEnum::FloatType(ty) => ty.get_undef().as_basic_value_enum(),
+            BasicTypeEnum::IntType(ty) => ty.get_undef().as_basic_value_enum(),
+            BasicTypeEnum::PointerType(ty) => ty.get_undef().as_basic_value_enum(),
+            BasicTypeEnum::StructType(ty) => ty.get_undef().as_basic_value_enum(),
+            BasicTypeEnum::VectorType(ty) => ty.get_undef().as_basic_value_enum(),
+        }
+    }
+
+    /// Creates a poison instance of this type. Unlike undef, which may take any value at each
+    /// use, poison propagates through every instruction that depends on it and only becomes
+    /// undefined behavior when it reaches a side effect, such as a branch condition or a store address.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::BasicType;
+    ///
+    /// let context = Context::create();
+    /// let f64_type = context.f64_type().as_basic_type_enum();
+    /// let f64_poison = f64_type.get_poison();
+    ///
+    /// assert!(f64_poison.into_float_value().is_poison());
+    /// ```
+    #[llvm_versions(12.0..=latest)]
+    pub fn get_poison(self) -> BasicValueEnum<'ctx> {
+        match self {
+            BasicTypeEnum::ArrayType(ty) => ty.get_poison().as_basic_value_enum(),
+            BasicTypeEnum::FloatType(ty) => ty.get_poison().as_basic_value_enum(),
+            BasicTypeEnum::IntType(ty) => ty.get_poison().as_basic_value_enum(),
+            BasicTypeEnum::PointerType(ty) => ty.get_poison().as_basic_value_enum(),
+            BasicTypeEnum::StructType(ty) => ty.get_poison().as_basic_value_enum(),
+            BasicTypeEnum::VectorType(ty) => ty.get_poison().as_basic_value_enum(),
+        }
+    }
+
     /// Print the definition of a `BasicTypeEnum` to `LLVMString`.
     pub fn print_to_string(self) -> LLVMString {
         match self {
@@ -88,7 +88,11 @@ impl<'ctx> IntType<'ctx> {
         }
     }
 
-    /// Creates an `IntValue` repesenting a constant value of this `IntType`. It will be automatically assigned this `IntType`'s `Context`.
+    /// Creates an `IntValue` representing a constant value of this `IntType`. It will be automatically assigned this `IntType`'s `Context`.
+    ///
+    /// `value` is truncated to types narrower than 64 bits. For wider types, `sign_extend`
+    /// decides whether the upper bits are filled with copies of the sign bit of `value`
+    /// rather than zeros, so that a negative number cast to `u64` keeps its value.
     ///
     /// # Example
     /// ```no_run
@@ -98,8 +102,10 @@ impl<'ctx> IntType<'ctx> {
     /// let context = Context::create();
     /// let i32_type = context.i32_type();
     /// let i32_value = i32_type.const_int(42, false);
+    /// let i128_minus_one = context.i128_type().const_int(-1i64 as u64, true);
+    ///
+    /// assert_eq!(i128_minus_one, context.i128_type().const_all_ones());
     /// ```
-    // TODOC: Maybe better explain sign extension
     pub fn const_int(self, value: u64, sign_extend: bool) -> IntValue<'ctx> {
         unsafe { IntValue::new(LLVMConstInt(self.as_type_ref(), value, sign_extend as i32)) }
     }
@@ -366,6 +372,23 @@ impl<'ctx> IntType<'ctx> {
         unsafe { IntValue::new(self.int_type.get_undef()) }
     }
 
+    /// Creates a poison instance of an `IntType`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let i8_poison = i8_type.get_poison();
+    ///
+    /// assert!(i8_poison.is_poison());
+    /// ```
+    #[llvm_versions(12.0..=latest)]
+    pub fn get_poison(self) -> IntValue<'ctx> {
+        unsafe { IntValue::new(self.int_type.get_poison()) }
+    }
+
     /// Creates a `GenericValue` for use with `ExecutionEngine`s.
     pub fn create_generic_value(self, value: u64, is_signed: bool) -> GenericValue<'ctx> {
         unsafe { GenericValue::new(LLVMCreateGenericValueOfInt(self.as_type_ref(), value, is_signed as i32)) }
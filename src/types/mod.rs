@@ -134,6 +134,13 @@ impl<'ctx> Type<'ctx> {
         unsafe { LLVMGetUndef(self.ty) }
     }
 
+    #[llvm_versions(12.0..=latest)]
+    fn get_poison(self) -> LLVMValueRef {
+        use llvm_sys::core::LLVMGetPoison;
+
+        unsafe { LLVMGetPoison(self.ty) }
+    }
+
     fn get_alignment(self) -> IntValue<'ctx> {
         unsafe { IntValue::new(LLVMAlignOf(self.ty)) }
     }
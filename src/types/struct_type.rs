@@ -107,6 +107,9 @@ impl<'ctx> StructType<'ctx> {
     // TODO: impl it only for StructType<T*>?
     /// Gets the size of this `StructType`. Value may vary depending on the target architecture.
     ///
+    /// The size is an `i64` constant expression, which is folded to a plain constant once the
+    /// module's target data is known. Returns `None` for opaque structs, which have no size.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -255,7 +258,7 @@ impl<'ctx> StructType<'ctx> {
     ///
     /// let context = Context::create();
     /// let f32_type = context.f32_type();
-    /// let struct_type = context.struct_type(&[f32_type.into(), f32_type.into()], false);
+    /// let struct_type = context.struct_type(&[f32_type.into(), f32_type.into()], true);
     ///
     /// assert!(struct_type.is_packed());
     /// ```
@@ -352,10 +355,30 @@ impl<'ctx> StructType<'ctx> {
         unsafe { StructValue::new(self.struct_type.get_undef()) }
     }
 
+    /// Creates a poison instance of a `StructType`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f32_type = context.f32_type();
+    /// let i8_type = context.i8_type();
+    /// let struct_type = context.struct_type(&[f32_type.into(), i8_type.into()], false);
+    /// let struct_type_poison = struct_type.get_poison();
+    ///
+    /// assert!(struct_type_poison.is_poison());
+    /// ```
+    #[llvm_versions(12.0..=latest)]
+    pub fn get_poison(self) -> StructValue<'ctx> {
+        unsafe { StructValue::new(self.struct_type.get_poison()) }
+    }
+
     /// Defines the body of a `StructType`.
     ///
-    /// If the struct is an opaque type, it will no longer be after this call.
-    /// 
+    /// If the struct is an opaque type, it will no longer be after this call. Returns
+    /// whether the struct was opaque beforehand.
+    ///
     /// Resetting the `packed` state of a non-opaque struct type may not work.
     ///
     /// # Example
@@ -103,7 +103,7 @@ impl<'ctx> VectorType<'ctx> {
     /// let f32_val2 = f32_type.const_float(2.);
     /// let f32_vec_val = VectorType::const_vector(&[f32_val, f32_val2]);
     ///
-    /// assert!(f32_vec_val.is_constant_vector());
+    /// assert!(f32_vec_val.is_constant_data_vector());
     /// ```
     pub fn const_vector<V: BasicValue<'ctx>>(values: &[V]) -> VectorValue<'ctx> {
         let mut values: Vec<LLVMValueRef> = values.iter().map(|val| val.as_value_ref()).collect();
@@ -149,6 +149,24 @@ impl<'ctx> VectorType<'ctx> {
         unsafe { VectorValue::new(self.vec_type.get_undef()) }
     }
 
+    /// Creates a poison instance of a `VectorType`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f32_type = context.f32_type();
+    /// let f32_vec_type = f32_type.vec_type(7);
+    /// let f32_vec_poison = f32_vec_type.get_poison();
+    ///
+    /// assert!(f32_vec_poison.is_poison());
+    /// ```
+    #[llvm_versions(12.0..=latest)]
+    pub fn get_poison(self) -> VectorValue<'ctx> {
+        unsafe { VectorValue::new(self.vec_type.get_poison()) }
+    }
+
     // SubType: VectorType<BT> -> BT?
     /// Gets the element type of this `VectorType`.
     ///
@@ -49,6 +49,12 @@ impl<'ctx> ArrayValue<'ctx> {
         self.array_value.is_undef()
     }
 
+    /// Determines whether or not this value is poison.
+    #[llvm_versions(12.0..=latest)]
+    pub fn is_poison(self) -> bool {
+        self.array_value.is_poison()
+    }
+
     /// Prints this `ArrayValue` to standard error.
     pub fn print_to_stderr(self) {
         self.array_value.print_to_stderr()
@@ -60,7 +66,11 @@ impl<'ctx> ArrayValue<'ctx> {
     }
 
     /// Replaces all uses of this value with another value of the same type.
-    /// If used incorrectly this may result in invalid IR.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has a different element type or length, such as a `[4 x i8]`
+    /// replacing a `[3 x i8]`.
     pub fn replace_all_uses_with(self, other: ArrayValue<'ctx>) {
         self.array_value.replace_all_uses_with(other.as_value_ref())
     }
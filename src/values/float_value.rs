@@ -54,6 +54,12 @@ impl<'ctx> FloatValue<'ctx> {
         self.float_value.is_undef()
     }
 
+    /// Determines whether or not this value is poison.
+    #[llvm_versions(12.0..=latest)]
+    pub fn is_poison(self) -> bool {
+        self.float_value.is_poison()
+    }
+
     pub fn print_to_stderr(self) {
         self.float_value.print_to_stderr()
     }
@@ -170,6 +176,11 @@ impl<'ctx> FloatValue<'ctx> {
         Some((constant, lossy == 1))
     }
 
+    /// Replaces all uses of this value with another value of the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has a different floating point type, such as an `f64` replacing an `f32`.
     pub fn replace_all_uses_with(self, other: FloatValue<'ctx>) {
         self.float_value.replace_all_uses_with(other.as_value_ref())
     }
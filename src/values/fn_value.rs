@@ -20,7 +20,7 @@ use std::marker::PhantomData;
 use std::mem::forget;
 
 use crate::attributes::{Attribute, AttributeLoc};
-use crate::basic_block::BasicBlock;
+use crate::basic_block::{BasicBlock, BasicBlockIterator};
 #[llvm_versions(7.0..=latest)]
 use crate::debug_info::DISubprogram;
 use crate::module::Linkage;
@@ -47,10 +47,31 @@ impl<'ctx> FunctionValue<'ctx> {
         })
     }
 
+    /// Gets the `Linkage` of this function.
     pub fn get_linkage(self) -> Linkage {
         unsafe { LLVMGetLinkage(self.as_value_ref()).into() }
     }
 
+    /// Sets the `Linkage` of this function, for example to make it `Internal` once it is
+    /// known not to be needed outside of its module.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Linkage;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    ///
+    /// assert_eq!(fn_value.get_linkage(), Linkage::External);
+    ///
+    /// fn_value.set_linkage(Linkage::Internal);
+    ///
+    /// assert_eq!(fn_value.get_linkage(), Linkage::Internal);
+    /// ```
     pub fn set_linkage(self, linkage: Linkage) {
         unsafe { LLVMSetLinkage(self.as_value_ref(), linkage.into()) }
     }
@@ -68,6 +89,28 @@ impl<'ctx> FunctionValue<'ctx> {
     }
 
     // FIXME: Better error returns, code 1 is error
+    /// Checks this function for malformed IR, such as a `BasicBlock` missing its terminator,
+    /// without verifying the rest of its module. Returns `true` if the function is valid.
+    /// When `print` is set, the reason for a failure is printed to stderr.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let fn_value = module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// assert!(!fn_value.verify(false));
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_return(None);
+    ///
+    /// assert!(fn_value.verify(false));
+    /// ```
     pub fn verify(self, print: bool) -> bool {
         let action = if print {
             LLVMVerifierFailureAction::LLVMPrintMessageAction
@@ -109,28 +152,59 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { Some(BasicValueEnum::new(param)) }
     }
 
+    /// Gets the first `BasicBlock` of this function, or `None` if it is only a declaration.
     pub fn get_first_basic_block(self) -> Option<BasicBlock<'ctx>> {
         unsafe { BasicBlock::new(LLVMGetFirstBasicBlock(self.as_value_ref())) }
     }
 
+    /// Gets the entry `BasicBlock` of this function, where execution starts, or `None` if
+    /// it is only a declaration. This is always the first `BasicBlock` of the function.
+    pub fn get_entry_basic_block(self) -> Option<BasicBlock<'ctx>> {
+        // LLVMGetEntryBasicBlock is undefined behavior on declarations, so don't use it
+        self.get_first_basic_block()
+    }
+
+    /// Gets the parameter at the given index, or `None` if the function has fewer parameters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    ///
+    /// let rhs = fn_value.get_nth_param(1).unwrap();
+    ///
+    /// rhs.set_name("rhs");
+    ///
+    /// assert_eq!(fn_value.get_nth_param(1), Some(rhs));
+    /// assert!(fn_value.get_nth_param(2).is_none());
+    /// ```
     pub fn get_nth_param(self, nth: u32) -> Option<BasicValueEnum<'ctx>> {
         let count = self.count_params();
 
-        if nth + 1 > count {
+        if nth >= count {
             return None;
         }
 
         unsafe { Some(BasicValueEnum::new(LLVMGetParam(self.as_value_ref(), nth))) }
     }
 
+    /// Counts the number of parameters this function takes.
     pub fn count_params(self) -> u32 {
         unsafe { LLVMCountParams(self.fn_value.value) }
     }
 
+    /// Counts the number of `BasicBlock`s in this function.
     pub fn count_basic_blocks(self) -> u32 {
         unsafe { LLVMCountBasicBlocks(self.as_value_ref()) }
     }
 
+    /// Gets all of the `BasicBlock`s of this function, in order.
     pub fn get_basic_blocks(self) -> Vec<BasicBlock<'ctx>> {
         let count = self.count_basic_blocks();
         let mut raw_vec: Vec<LLVMBasicBlockRef> = Vec::with_capacity(count as usize);
@@ -150,6 +224,28 @@ impl<'ctx> FunctionValue<'ctx> {
             .collect()
     }
 
+    /// Gets an iterator over the `BasicBlock`s of this function, in order. Unlike
+    /// `get_basic_blocks`, the blocks are walked lazily without allocating a `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let exit = context.append_basic_block(fn_value, "exit");
+    ///
+    /// assert_eq!(fn_value.get_basic_block_iter().collect::<Vec<_>>(), [entry, exit]);
+    /// ```
+    pub fn get_basic_block_iter(self) -> BasicBlockIterator<'ctx> {
+        BasicBlockIterator::from_function(self)
+    }
+
+    /// Gets an iterator over the parameters of this function, in order.
     pub fn get_param_iter(self) -> ParamValueIter<'ctx> {
         ParamValueIter {
             param_iter_value: self.fn_value.value,
@@ -158,6 +254,7 @@ impl<'ctx> FunctionValue<'ctx> {
         }
     }
 
+    /// Gets all of the parameters of this function, in order.
     pub fn get_params(self) -> Vec<BasicValueEnum<'ctx>> {
         let count = self.count_params();
         let mut raw_vec: Vec<LLVMValueRef> = Vec::with_capacity(count as usize);
@@ -194,6 +291,28 @@ impl<'ctx> FunctionValue<'ctx> {
     }
 
     // TODO: Look for ways to prevent use after delete but maybe not possible
+    /// Removes this function from its module and frees it, along with its `BasicBlock`s.
+    ///
+    /// # Safety
+    ///
+    /// `FunctionValue` is `Copy`, so consuming `self` doesn't stop other copies from being
+    /// used afterwards. The caller must ensure that no copy of this function, or of any
+    /// value or block within it, is used after deletion, and that it has no remaining uses
+    /// such as calls from other functions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_value = module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+    ///
+    /// unsafe { fn_value.delete() };
+    ///
+    /// assert!(module.get_function("my_fn").is_none());
+    /// ```
     pub unsafe fn delete(self) {
         LLVMDeleteFunction(self.as_value_ref())
     }
@@ -236,10 +355,31 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMGetIntrinsicID(self.as_value_ref()) }
     }
 
+    /// Gets the calling convention of this function. See `set_call_conventions` for the meaning
+    /// of the returned id.
     pub fn get_call_conventions(self) -> u32 {
         unsafe { LLVMGetFunctionCallConv(self.as_value_ref()) }
     }
 
+    /// Sets the calling convention of this function, using LLVM's numeric ids. Common ones are
+    /// `0` (C), `8` (`fastcc`), `9` (`coldcc`) and `64` (`x86_stdcallcc`). Calls to this function
+    /// must use the same convention, see `CallSiteValue::set_call_convention`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_value = module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+    ///
+    /// assert_eq!(fn_value.get_call_conventions(), 0);
+    ///
+    /// fn_value.set_call_conventions(8);
+    ///
+    /// assert_eq!(fn_value.get_call_conventions(), 8);
+    /// ```
     pub fn set_call_conventions(self, call_conventions: u32) {
         unsafe { LLVMSetFunctionCallConv(self.as_value_ref(), call_conventions) }
     }
@@ -254,6 +394,12 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMSetGC(self.as_value_ref(), c_string.as_ptr()) }
     }
 
+    /// Replaces all uses of this value with another value of the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has a different signature, such as a `fn(i32) -> i32` replacing
+    /// a `fn() -> i32`.
     pub fn replace_all_uses_with(self, other: FunctionValue<'ctx>) {
         self.fn_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -85,17 +85,45 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { Some(GlobalValue::new(value)) }
     }
 
+    /// Gets the `DLLStorageClass` of this `GlobalValue`.
     pub fn get_dll_storage_class(self) -> DLLStorageClass {
         let dll_storage_class = unsafe { LLVMGetDLLStorageClass(self.as_value_ref()) };
 
         DLLStorageClass::new(dll_storage_class)
     }
 
+    /// Sets the `DLLStorageClass` of this `GlobalValue`, which only has an effect on Windows targets.
     pub fn set_dll_storage_class(self, dll_storage_class: DLLStorageClass) {
         unsafe { LLVMSetDLLStorageClass(self.as_value_ref(), dll_storage_class.into()) }
     }
 
+    /// Gets the initializer of this global variable. Returns `None` for external declarations,
+    /// which have no initializer, and for functions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i8_type = context.i8_type();
+    /// let global = module.add_global(i8_type, None, "my_global");
+    ///
+    /// assert!(global.get_initializer().is_none());
+    ///
+    /// global.set_initializer(&i8_type.const_int(1, false));
+    ///
+    /// assert_eq!(global.get_initializer().unwrap().into_int_value(), i8_type.const_int(1, false));
+    /// ```
     pub fn get_initializer(self) -> Option<BasicValueEnum<'ctx>> {
+        use llvm_sys::core::LLVMIsAGlobalVariable;
+
+        // LLVMGetInitializer assumes the value is a global variable
+        if unsafe { LLVMIsAGlobalVariable(self.as_value_ref()) }.is_null() {
+            return None;
+        }
+
         let value = unsafe { LLVMGetInitializer(self.as_value_ref()) };
 
         if value.is_null() {
@@ -105,28 +133,50 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { Some(BasicValueEnum::new(value)) }
     }
 
+    /// Sets the initializer of this global variable, turning an external declaration into a
+    /// definition. The value must be a constant of the global's type, and this must not be
+    /// called on a function.
     // SubType: This input type should be tied to the BasicType
     pub fn set_initializer(self, value: &dyn BasicValue<'ctx>) {
         unsafe { LLVMSetInitializer(self.as_value_ref(), value.as_value_ref()) }
     }
 
+    /// Determines whether or not this `GlobalValue` is thread local.
     pub fn is_thread_local(self) -> bool {
         unsafe { LLVMIsThreadLocal(self.as_value_ref()) == 1 }
     }
 
-    // TODOC: Setting this to true is the same as setting GeneralDynamicTLSModel
+    /// Marks this `GlobalValue` as thread local or not. Setting this to true is
+    /// the same as setting the `ThreadLocalMode::GeneralDynamicTLSModel` mode.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::ThreadLocalMode;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let global = module.add_global(context.i32_type(), None, "my_global");
+    ///
+    /// global.set_thread_local(true);
+    ///
+    /// assert!(global.is_thread_local());
+    /// assert_eq!(global.get_thread_local_mode(), Some(ThreadLocalMode::GeneralDynamicTLSModel));
+    /// ```
     pub fn set_thread_local(self, is_thread_local: bool) {
         unsafe { LLVMSetThreadLocal(self.as_value_ref(), is_thread_local as i32) }
     }
 
+    /// Gets the `ThreadLocalMode` of this `GlobalValue`, or `None` if it is not thread local.
     pub fn get_thread_local_mode(self) -> Option<ThreadLocalMode> {
         let thread_local_mode = unsafe { LLVMGetThreadLocalMode(self.as_value_ref()) };
 
         ThreadLocalMode::new(thread_local_mode)
     }
 
-    // REVIEW: Does this have any bad behavior if it isn't thread local or just a noop?
-    // or should it call self.set_thread_local(true)?
+    /// Sets the `ThreadLocalMode` of this `GlobalValue`, making it thread local if it
+    /// wasn't already. `None` makes this `GlobalValue` no longer thread local.
     pub fn set_thread_local_mode(self, thread_local_mode: Option<ThreadLocalMode>) {
         let thread_local_mode = match thread_local_mode {
             Some(mode) => mode.as_llvm_mode(),
@@ -161,21 +211,29 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { LLVMIsDeclaration(self.as_value_ref()) == 1 }
     }
 
+    /// Determines whether or not this `GlobalValue` is marked `unnamed_addr`, meaning that
+    /// its address is not significant and it may be merged with identical constants.
     #[llvm_versions(4.0..=6.0)]
     pub fn has_unnamed_addr(self) -> bool {
         unsafe { LLVMHasUnnamedAddr(self.as_value_ref()) == 1 }
     }
 
+    /// Determines whether or not this `GlobalValue` is marked `unnamed_addr`, meaning that
+    /// its address is not significant and it may be merged with identical constants.
+    /// A `local_unnamed_addr` global is not considered `unnamed_addr` by this method.
     #[llvm_versions(7.0..=latest)]
     pub fn has_unnamed_addr(self) -> bool {
         unsafe { LLVMGetUnnamedAddress(self.as_value_ref()) == LLVMUnnamedAddr::LLVMGlobalUnnamedAddr }
     }
 
+    /// Marks this `GlobalValue` as `unnamed_addr` or not.
     #[llvm_versions(4.0..=6.0)]
     pub fn set_unnamed_addr(self, has_unnamed_addr: bool) {
         unsafe { LLVMSetUnnamedAddr(self.as_value_ref(), has_unnamed_addr as i32) }
     }
 
+    /// Marks this `GlobalValue` as `unnamed_addr` or not. This is the same as setting
+    /// `UnnamedAddress::Global` or `UnnamedAddress::None` with `set_unnamed_address`.
     #[llvm_versions(7.0..=latest)]
     pub fn set_unnamed_addr(self, has_unnamed_addr: bool) {
         unsafe {
@@ -187,10 +245,31 @@ impl<'ctx> GlobalValue<'ctx> {
         }
     }
 
+    /// Determines whether or not this `GlobalValue` is marked as constant.
     pub fn is_constant(self) -> bool {
         unsafe { LLVMIsGlobalConstant(self.as_value_ref()) == 1 }
     }
 
+    /// Marks this `GlobalValue` as constant or not. A constant global's initializer
+    /// will never be modified, which allows loads from it to be folded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Linkage;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let table = module.add_global(i32_type, None, "table");
+    ///
+    /// table.set_initializer(&i32_type.const_int(42, false));
+    /// table.set_linkage(Linkage::Internal);
+    /// table.set_constant(true);
+    ///
+    /// assert!(table.is_constant());
+    /// ```
     pub fn set_constant(self, is_constant: bool) {
         unsafe { LLVMSetGlobalConstant(self.as_value_ref(), is_constant as i32) }
     }
@@ -203,10 +282,29 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { LLVMSetExternallyInitialized(self.as_value_ref(), externally_initialized as i32) }
     }
 
+    /// Sets the `GlobalVisibility` of this `GlobalValue`. Local linkages such as
+    /// `Linkage::Private` require `GlobalVisibility::Default`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::GlobalVisibility;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_value = module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+    /// let global = fn_value.as_global_value();
+    ///
+    /// global.set_visibility(GlobalVisibility::Hidden);
+    ///
+    /// assert_eq!(global.get_visibility(), GlobalVisibility::Hidden);
+    /// ```
     pub fn set_visibility(self, visibility: GlobalVisibility) {
         unsafe { LLVMSetVisibility(self.as_value_ref(), visibility.into()) }
     }
 
+    /// Gets the `GlobalVisibility` of this `GlobalValue`.
     pub fn get_visibility(self) -> GlobalVisibility {
         let visibility = unsafe { LLVMGetVisibility(self.as_value_ref()) };
 
@@ -231,11 +329,66 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { PointerValue::new(self.as_value_ref()) }
     }
 
+    /// Replaces all uses of this `GlobalValue` with another one of the same type, such as
+    /// when redirecting references from a duplicate global to a canonical one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` does not have the same type as this `GlobalValue`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::BasicValue;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let old = module.add_global(i32_type, None, "old");
+    /// let new = module.add_global(i32_type, None, "new");
+    ///
+    /// old.replace_all_uses_with(new);
+    ///
+    /// assert!(old.as_pointer_value().get_first_use().is_none());
+    /// ```
+    pub fn replace_all_uses_with(self, other: GlobalValue<'ctx>) {
+        self.global_value.replace_all_uses_with(other.as_value_ref())
+    }
+
+    /// Gets the alignment of this `GlobalValue` in bytes, or 0 if none was set explicitly.
     pub fn get_alignment(self) -> u32 {
         unsafe { LLVMGetAlignment(self.as_value_ref()) }
     }
 
+    /// Sets the alignment of this `GlobalValue` in bytes. An alignment of 0 lets the target
+    /// pick one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alignment` is neither 0 nor a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let global = module.add_global(context.i32_type(), None, "my_global");
+    ///
+    /// assert_eq!(global.get_alignment(), 0);
+    ///
+    /// global.set_alignment(16);
+    ///
+    /// assert_eq!(global.get_alignment(), 16);
+    /// ```
     pub fn set_alignment(self, alignment: u32) {
+        assert!(
+            alignment == 0 || alignment.is_power_of_two(),
+            "Alignment is not a power of 2!"
+        );
+
         unsafe { LLVMSetAlignment(self.as_value_ref(), alignment) }
     }
 
@@ -267,6 +420,7 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { LLVMSetComdat(self.as_value_ref(), comdat.0) }
     }
 
+    /// Gets the `UnnamedAddress` of this `GlobalValue`.
     #[llvm_versions(7.0..=latest)]
     pub fn get_unnamed_address(self) -> UnnamedAddress {
         use llvm_sys::core::LLVMGetUnnamedAddress;
@@ -276,6 +430,26 @@ impl<'ctx> GlobalValue<'ctx> {
         UnnamedAddress::new(unnamed_address)
     }
 
+    /// Sets the `UnnamedAddress` of this `GlobalValue`, which lets the optimizer merge it
+    /// with identical constants when its address is insignificant.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::UnnamedAddress;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let string = context.const_string(b"hello", true);
+    /// let global = module.add_global(string.get_type(), None, "hello");
+    ///
+    /// global.set_initializer(&string);
+    /// global.set_constant(true);
+    /// global.set_unnamed_address(UnnamedAddress::Global);
+    ///
+    /// assert_eq!(global.get_unnamed_address(), UnnamedAddress::Global);
+    /// ```
     #[llvm_versions(7.0..=latest)]
     pub fn set_unnamed_address(self, address: UnnamedAddress) {
         use llvm_sys::core::LLVMSetUnnamedAddress;
@@ -283,10 +457,12 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { LLVMSetUnnamedAddress(self.as_value_ref(), address.into()) }
     }
 
+    /// Gets the `Linkage` of this `GlobalValue`.
     pub fn get_linkage(self) -> Linkage {
         unsafe { LLVMGetLinkage(self.as_value_ref()).into() }
     }
 
+    /// Sets the `Linkage` of this `GlobalValue`.
     pub fn set_linkage(self, linkage: Linkage) {
         unsafe { LLVMSetLinkage(self.as_value_ref(), linkage.into()) }
     }
@@ -105,6 +105,9 @@ pub enum InstructionOpcode {
     ZExt,
 }
 
+/// A value which is an instruction inside a `BasicBlock`. Most `Builder` methods which do not
+/// produce a `BasicValue` return one, and a `BasicValue` produced by an instruction can be
+/// converted into one through `BasicValue::as_instruction_value`.
 #[derive(Debug, PartialEq, Eq, Copy, Hash)]
 pub struct InstructionValue<'ctx> {
     instruction_value: Value<'ctx>,
@@ -176,12 +179,14 @@ impl<'ctx> InstructionValue<'ctx> {
         unsafe { AnyTypeEnum::new(self.instruction_value.get_type()) }
     }
 
+    /// Gets the `InstructionOpcode` of this `InstructionValue`, such as `Add` or `Br`.
     pub fn get_opcode(self) -> InstructionOpcode {
         let opcode = unsafe { LLVMGetInstructionOpcode(self.as_value_ref()) };
 
         InstructionOpcode::new(opcode)
     }
 
+    /// Gets the instruction preceding this one in its `BasicBlock`, if any.
     pub fn get_previous_instruction(self) -> Option<Self> {
         let value = unsafe { LLVMGetPreviousInstruction(self.as_value_ref()) };
 
@@ -192,6 +197,7 @@ impl<'ctx> InstructionValue<'ctx> {
         unsafe { Some(InstructionValue::new(value)) }
     }
 
+    /// Gets the instruction following this one in its `BasicBlock`, if any.
     pub fn get_next_instruction(self) -> Option<Self> {
         let value = unsafe { LLVMGetNextInstruction(self.as_value_ref()) };
 
@@ -202,11 +208,40 @@ impl<'ctx> InstructionValue<'ctx> {
         unsafe { Some(InstructionValue::new(value)) }
     }
 
+    /// Unlinks this instruction from its `BasicBlock` and deletes it. The instruction
+    /// must not have any remaining uses and must not be used afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::{BasicValue, InstructionOpcode};
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("erase");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let function = module.add_function("f", fn_type, None);
+    /// let entry = context.append_basic_block(function, "entry");
+    /// let param = function.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let unused = builder.build_int_add(param, param, "unused");
+    ///
+    /// builder.build_return(Some(&param));
+    /// unused.as_instruction_value().unwrap().erase_from_basic_block();
+    ///
+    /// assert_eq!(entry.get_first_instruction().unwrap().get_opcode(), InstructionOpcode::Return);
+    /// ```
     // REVIEW: Potentially unsafe if parent BB or grandparent fn were removed?
     pub fn erase_from_basic_block(self) {
         unsafe { LLVMInstructionEraseFromParent(self.as_value_ref()) }
     }
 
+    /// Unlinks this instruction from its `BasicBlock` without deleting it, so that it
+    /// may be reinserted elsewhere, for example with `Builder::insert_instruction`.
     // REVIEW: Potentially unsafe if parent BB or grandparent fn were removed?
     #[llvm_versions(4.0..=latest)]
     pub fn remove_from_basic_block(self) {
@@ -218,10 +253,12 @@ impl<'ctx> InstructionValue<'ctx> {
     // but I doubt LLVM returns null if the parent BB (or grandparent FN)
     // was deleted... Invalid memory is more likely. Cloned IV will have no
     // parent?
+    /// Gets the `BasicBlock` containing this instruction, or `None` if it is not inserted in one.
     pub fn get_parent(self) -> Option<BasicBlock<'ctx>> {
         unsafe { BasicBlock::new(LLVMGetInstructionParent(self.as_value_ref())) }
     }
 
+    /// Determines whether or not this instruction is a call marked as a tail call.
     pub fn is_tail_call(self) -> bool {
         // LLVMIsTailCall has UB if the value is not an llvm::CallInst*.
         if self.get_opcode() == InstructionOpcode::Call {
@@ -231,6 +268,11 @@ impl<'ctx> InstructionValue<'ctx> {
         }
     }
 
+    /// Replaces all uses of this instruction with another instruction of the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` does not have the same type as this instruction.
     pub fn replace_all_uses_with(self, other: &InstructionValue<'ctx>) {
         self.instruction_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -272,6 +314,8 @@ impl<'ctx> InstructionValue<'ctx> {
 
     // SubTypes: Only apply to memory access instructions
     /// Sets whether or not a memory access instruction is volatile.
+    /// Volatile accesses are never removed, merged or reordered with other volatile accesses,
+    /// which makes them suitable for memory mapped IO.
     #[llvm_versions(10.0..=latest)]
     pub fn set_volatile(self, volatile: bool) -> Result<(), &'static str> {
         if !self.is_a_load_inst() && !self.is_a_store_inst() && !self.is_a_atomicrmw_inst() && !self.is_a_cmpxchg_inst()
@@ -322,6 +366,9 @@ impl<'ctx> InstructionValue<'ctx> {
 
     // SubTypes: Only apply to memory access instructions
     /// Sets atomic ordering on a memory access instruction.
+    ///
+    /// An atomic load or store must also have a non-zero alignment for the module to verify,
+    /// which some LLVM versions do not set by default. Use `set_alignment` to provide one.
     pub fn set_atomic_ordering(self, ordering: AtomicOrdering) -> Result<(), &'static str> {
         // Although fence and atomicrmw both have an ordering, the LLVM C API
         // does not support them. The cmpxchg instruction has two orderings and
@@ -664,8 +711,44 @@ impl<'ctx> InstructionValue<'ctx> {
         unsafe { Some(MetadataValue::new(metadata_value)) }
     }
 
-    /// Determines whether or not this `Instruction` has any associated metadata
-    /// `kind_id`.
+    /// Attaches a metadata node to this `Instruction` at a specific `kind_id`, replacing
+    /// any node previously attached at that kind. Kind ids can be obtained with
+    /// `Context::get_kind_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if `metadata` is not a node.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("prof");
+    /// let builder = context.create_builder();
+    /// let bool_type = context.bool_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = context.void_type().fn_type(&[bool_type.into()], false);
+    /// let function = module.add_function("branch", fn_type, None);
+    /// let entry = context.append_basic_block(function, "entry");
+    /// let then_block = context.append_basic_block(function, "then");
+    /// let else_block = context.append_basic_block(function, "else");
+    ///
+    /// builder.position_at_end(entry);
+    ///
+    /// let cond = function.get_first_param().unwrap().into_int_value();
+    /// let branch = builder.build_conditional_branch(cond, then_block, else_block);
+    /// let weights = context.metadata_node(&[
+    ///     context.metadata_string("branch_weights").into(),
+    ///     i32_type.const_int(99, false).into(),
+    ///     i32_type.const_int(1, false).into(),
+    /// ]);
+    ///
+    /// branch.set_metadata(weights, context.get_kind_id("prof")).unwrap();
+    ///
+    /// assert!(branch.get_metadata(context.get_kind_id("prof")).is_some());
+    /// ```
     pub fn set_metadata(self, metadata: MetadataValue<'ctx>, kind_id: u32) -> Result<(), &'static str> {
         if !metadata.is_node() {
             return Err("metadata is expected to be a node.");
@@ -56,6 +56,12 @@ impl<'ctx> IntValue<'ctx> {
         self.int_value.is_undef()
     }
 
+    /// Determines whether or not this value is poison.
+    #[llvm_versions(12.0..=latest)]
+    pub fn is_poison(self) -> bool {
+        self.int_value.is_poison()
+    }
+
     pub fn print_to_stderr(self) {
         self.int_value.print_to_stderr()
     }
@@ -346,6 +352,12 @@ impl<'ctx> IntValue<'ctx> {
         unsafe { Some(LLVMConstIntGetSExtValue(self.as_value_ref())) }
     }
 
+    /// Replaces all uses of this value with another value of the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` does not have the same type as this value, such as an `i64`
+    /// replacing an `i32`.
     pub fn replace_all_uses_with(self, other: IntValue<'ctx>) {
         self.int_value.replace_all_uses_with(other.as_value_ref())
     }
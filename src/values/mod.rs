@@ -145,6 +145,13 @@ impl<'ctx> Value<'ctx> {
         unsafe { LLVMIsUndef(self.value) == 1 }
     }
 
+    #[llvm_versions(12.0..=latest)]
+    fn is_poison(self) -> bool {
+        use llvm_sys::core::LLVMIsPoison;
+
+        unsafe { LLVMIsPoison(self.value) == 1 }
+    }
+
     fn get_type(self) -> LLVMTypeRef {
         unsafe { LLVMTypeOf(self.value) }
     }
@@ -160,6 +167,12 @@ impl<'ctx> Value<'ctx> {
     // REVIEW: I think this is memory safe, though it may result in an IR error
     // if used incorrectly, which is OK.
     fn replace_all_uses_with(self, other: LLVMValueRef) {
+        // LLVM asserts on mismatched types and otherwise silently produces broken IR.
+        assert!(
+            self.get_type() == unsafe { LLVMTypeOf(other) },
+            "Replacement value must have the same type as the value being replaced"
+        );
+
         // LLVM may infinite-loop when they aren't distinct, which is UB in C++.
         if self.value != other {
             unsafe { LLVMReplaceAllUsesWith(self.value, other) }
@@ -185,18 +198,25 @@ impl<'ctx> Value<'ctx> {
         }
 
         // On MacOS we need to remove ',' before section name
-        if cfg!(target_os = "macos") {
+        let name = if cfg!(target_os = "macos") {
             let name = unsafe { CStr::from_ptr(ptr) };
             let name_string = name.to_string_lossy();
             let mut chars = name_string.chars();
             if Some(',') == chars.next() {
-                Some(unsafe { CStr::from_ptr(ptr.add(1)) })
+                unsafe { CStr::from_ptr(ptr.add(1)) }
             } else {
-                Some(name)
+                name
             }
         } else {
-            Some(unsafe { CStr::from_ptr(ptr) })
+            unsafe { CStr::from_ptr(ptr) }
+        };
+
+        // An empty section is the same as no section at all
+        if name.to_bytes().is_empty() {
+            return None;
         }
+
+        Some(name)
     }
 
     /// Sets the section of the global value
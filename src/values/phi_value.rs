@@ -28,6 +28,39 @@ impl<'ctx> PhiValue<'ctx> {
         }
     }
 
+    /// Adds incoming `(value, block)` pairs to this phi. The phi takes `value` when control
+    /// arrived from the corresponding predecessor `block`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("phi");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[context.bool_type().into()], false);
+    /// let fn_value = module.add_function("select_one", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let then_block = context.append_basic_block(fn_value, "then");
+    /// let merge = context.append_basic_block(fn_value, "merge");
+    /// let cond = fn_value.get_first_param().unwrap().into_int_value();
+    /// let zero = i32_type.const_zero();
+    /// let one = i32_type.const_int(1, false);
+    ///
+    /// builder.position_at_end(entry);
+    /// builder.build_conditional_branch(cond, then_block, merge);
+    /// builder.position_at_end(then_block);
+    /// builder.build_unconditional_branch(merge);
+    /// builder.position_at_end(merge);
+    ///
+    /// let phi = builder.build_phi(i32_type, "result");
+    ///
+    /// phi.add_incoming(&[(&zero, entry), (&one, then_block)]);
+    ///
+    /// assert_eq!(phi.count_incoming(), 2);
+    /// ```
     pub fn add_incoming(self, incoming: &[(&dyn BasicValue<'ctx>, BasicBlock<'ctx>)]) {
         let (mut values, mut basic_blocks): (Vec<LLVMValueRef>, Vec<LLVMBasicBlockRef>) = {
             incoming
@@ -62,8 +95,7 @@ impl<'ctx> PhiValue<'ctx> {
         Some((value, basic_block))
     }
 
-    /// Gets the name of a `ArrayValue`. If the value is a constant, this will
-    /// return an empty string.
+    /// Gets the name of a `PhiValue`.
     pub fn get_name(&self) -> &CStr {
         self.phi_value.get_name()
     }
@@ -88,6 +120,11 @@ impl<'ctx> PhiValue<'ctx> {
             .expect("PhiValue should always be a Phi InstructionValue")
     }
 
+    /// Replaces all uses of this value with another value of the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` does not have the same type as this phi.
     pub fn replace_all_uses_with(self, other: &PhiValue<'ctx>) {
         self.phi_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -52,6 +52,12 @@ impl<'ctx> PointerValue<'ctx> {
         self.ptr_value.is_undef()
     }
 
+    /// Determines whether or not this value is poison.
+    #[llvm_versions(12.0..=latest)]
+    pub fn is_poison(self) -> bool {
+        self.ptr_value.is_poison()
+    }
+
     /// Determines whether or not a `PointerValue` is a constant.
     ///
     /// # Example
@@ -161,6 +167,11 @@ impl<'ctx> PointerValue<'ctx> {
         unsafe { PointerValue::new(LLVMConstAddrSpaceCast(self.as_value_ref(), ptr_type.as_type_ref())) }
     }
 
+    /// Replaces all uses of this value with another value of the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` does not have the same type as this pointer.
     pub fn replace_all_uses_with(self, other: PointerValue<'ctx>) {
         self.ptr_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -46,6 +46,12 @@ impl<'ctx> StructValue<'ctx> {
         self.struct_value.is_undef()
     }
 
+    /// Determines whether or not this value is poison.
+    #[llvm_versions(12.0..=latest)]
+    pub fn is_poison(self) -> bool {
+        self.struct_value.is_poison()
+    }
+
     pub fn print_to_stderr(self) {
         self.struct_value.print_to_stderr()
     }
@@ -54,6 +60,11 @@ impl<'ctx> StructValue<'ctx> {
         self.struct_value.as_instruction()
     }
 
+    /// Replaces all uses of this value with another value of the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has a different struct layout, such as `{ i32, i64 }` replacing `{ i32, i32 }`.
     pub fn replace_all_uses_with(self, other: StructValue<'ctx>) {
         self.struct_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -102,6 +102,9 @@ pub unsafe trait BasicValue<'ctx>: AnyValue<'ctx> {
         unsafe { Some(InstructionValue::new(self.as_value_ref())) }
     }
 
+    /// Gets the first use of this value, if any. The remaining uses can be walked
+    /// with `BasicValueUse::get_next_use`, and the instruction using the value is given
+    /// by `BasicValueUse::get_user`.
     fn get_first_use(&self) -> Option<BasicValueUse> {
         unsafe { Value::new(self.as_value_ref()).get_first_use() }
     }
@@ -80,6 +80,12 @@ impl<'ctx> VectorValue<'ctx> {
         self.vec_value.is_undef()
     }
 
+    /// Determines whether or not this value is poison.
+    #[llvm_versions(12.0..=latest)]
+    pub fn is_poison(self) -> bool {
+        self.vec_value.is_poison()
+    }
+
     pub fn as_instruction(self) -> Option<InstructionValue<'ctx>> {
         self.vec_value.as_instruction()
     }
@@ -99,6 +105,12 @@ impl<'ctx> VectorValue<'ctx> {
         }
     }
 
+    /// Replaces all uses of this value with another value of the same type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has a different element type or length, such as a `<4 x i32>`
+    /// replacing a `<2 x i32>`.
     pub fn replace_all_uses_with(self, other: VectorValue<'ctx>) {
         self.vec_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -1,5 +1,6 @@
 use inkwell::attributes::{Attribute, AttributeLoc};
 use inkwell::context::Context;
+use inkwell::memory_buffer::MemoryBuffer;
 
 #[test]
 fn test_enum_attribute_kinds() {
@@ -208,3 +209,95 @@ fn test_attributes_on_call_site_values() {
         .get_enum_attribute(AttributeLoc::Return, align_attribute)
         .is_some());
 }
+
+#[test]
+fn test_function_attributes_in_ir() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry_bb = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry_bb);
+    builder.build_return(Some(&fn_value.get_first_param().unwrap()));
+
+    let nounwind_id = Attribute::get_named_enum_kind_id("nounwind");
+    let noinline_id = Attribute::get_named_enum_kind_id("noinline");
+    let noundef_id = Attribute::get_named_enum_kind_id("noundef");
+
+    assert_ne!(nounwind_id, 0);
+    assert_ne!(noinline_id, 0);
+
+    let nounwind = context.create_enum_attribute(nounwind_id, 0);
+    let noinline = context.create_enum_attribute(noinline_id, 0);
+
+    fn_value.add_attribute(AttributeLoc::Function, nounwind);
+    fn_value.add_attribute(AttributeLoc::Function, noinline);
+
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Function), 2);
+    assert!(fn_value
+        .get_enum_attribute(AttributeLoc::Function, nounwind_id)
+        .is_some());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("define i32 @my_fn(i32 %0) #0"), "{}", ir);
+    assert!(ir.contains("attributes #0 = { noinline nounwind }"), "{}", ir);
+
+    if noundef_id != 0 {
+        fn_value.add_attribute(AttributeLoc::Param(0), context.create_enum_attribute(noundef_id, 0));
+
+        assert!(module.to_string().contains("define i32 @my_fn(i32 noundef %0) #0"));
+    }
+
+    fn_value.remove_enum_attribute(AttributeLoc::Function, noinline_id);
+
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Function), 1);
+    assert!(module.to_string().contains("attributes #0 = { nounwind }"));
+}
+
+#[test]
+fn test_string_attributes_round_trip_through_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let builder = context.create_builder();
+    let entry_bb = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry_bb);
+    builder.build_return(None);
+
+    fn_value.add_attribute(
+        AttributeLoc::Function,
+        context.create_string_attribute("no-builtins", ""),
+    );
+    fn_value.add_attribute(
+        AttributeLoc::Function,
+        context.create_string_attribute("frame-pointer", "all"),
+    );
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("\"frame-pointer\"=\"all\""), "{}", ir);
+    assert!(ir.contains("\"no-builtins\""), "{}", ir);
+
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(ir.as_bytes(), "my_mod");
+    let parsed_module = context.create_module_from_ir(memory_buffer).unwrap();
+    let parsed_fn = parsed_module.get_function("my_fn").unwrap();
+
+    let no_builtins = parsed_fn
+        .get_string_attribute(AttributeLoc::Function, "no-builtins")
+        .unwrap();
+    let frame_pointer = parsed_fn
+        .get_string_attribute(AttributeLoc::Function, "frame-pointer")
+        .unwrap();
+
+    assert!(no_builtins.is_string());
+    assert_eq!(no_builtins.get_string_kind_id().to_str(), Ok("no-builtins"));
+    assert_eq!(no_builtins.get_string_value().to_str(), Ok(""));
+    assert_eq!(frame_pointer.get_string_value().to_str(), Ok("all"));
+}
@@ -1,5 +1,6 @@
 use inkwell::context::Context;
 use inkwell::values::InstructionOpcode;
+use inkwell::IntPredicate;
 
 #[test]
 fn test_basic_block_ordering() {
@@ -104,6 +105,57 @@ fn test_get_basic_blocks() {
     assert_eq!(basic_blocks[0], basic_block);
 }
 
+#[test]
+fn test_basic_block_and_instruction_iterators() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("abs", fn_type, None);
+
+    assert_eq!(function.get_basic_block_iter().count(), 0);
+
+    let entry = context.append_basic_block(function, "entry");
+    let negate = context.append_basic_block(function, "negate");
+    let done = context.append_basic_block(function, "done");
+    let x = function.get_first_param().unwrap().into_int_value();
+
+    assert_eq!(entry.get_instructions().count(), 0);
+
+    builder.position_at_end(entry);
+
+    let is_negative = builder.build_int_compare(IntPredicate::SLT, x, i32_type.const_zero(), "is_negative");
+
+    builder.build_conditional_branch(is_negative, negate, done);
+    builder.position_at_end(negate);
+
+    let negated = builder.build_int_neg(x, "negated");
+
+    builder.build_return(Some(&negated));
+    builder.position_at_end(done);
+    builder.build_return(Some(&x));
+
+    let blocks: Vec<_> = function.get_basic_block_iter().collect();
+
+    assert_eq!(blocks, function.get_basic_blocks());
+
+    let instruction_count: usize = function
+        .get_basic_block_iter()
+        .map(|block| block.get_instructions().count())
+        .sum();
+
+    assert_eq!(instruction_count, 5);
+
+    let opcodes: Vec<_> = entry
+        .get_instructions()
+        .map(|instruction| instruction.get_opcode())
+        .collect();
+
+    assert_eq!(opcodes, [InstructionOpcode::ICmp, InstructionOpcode::Br]);
+    assert_eq!(negate.get_instructions().last(), negate.get_terminator());
+}
+
 #[test]
 fn test_get_terminator() {
     let context = Context::create();
@@ -118,7 +170,6 @@ fn test_get_terminator() {
 
     builder.position_at_end(basic_block);
 
-    // REVIEW: What's the difference between a terminator and last instruction?
     assert!(basic_block.get_terminator().is_none());
     assert!(basic_block.get_first_instruction().is_none());
     assert!(basic_block.get_last_instruction().is_none());
@@ -140,6 +191,63 @@ fn test_get_terminator() {
     assert_eq!(basic_block.get_last_instruction(), basic_block.get_terminator());
 }
 
+#[test]
+fn test_reorder_and_delete_blocks() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let function = module.add_function("testing", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let exit = context.append_basic_block(function, "exit");
+    let body = context.append_basic_block(function, "body");
+    let dead = context.append_basic_block(function, "dead");
+
+    builder.position_at_end(entry);
+    builder.build_unconditional_branch(body);
+    builder.position_at_end(body);
+
+    let value = builder.build_int_add(i32_type.const_int(1, false), i32_type.const_int(2, false), "value");
+
+    // An unterminated block has a last instruction, but no terminator
+    builder.position_at_end(exit);
+    builder.build_alloca(i32_type, "slot");
+
+    assert!(exit.get_last_instruction().is_some());
+    assert!(exit.get_terminator().is_none());
+
+    builder.build_return(Some(&value));
+
+    assert_eq!(exit.get_terminator(), exit.get_last_instruction());
+
+    builder.position_at_end(body);
+    builder.build_unconditional_branch(exit);
+    builder.position_at_end(dead);
+    builder.build_unreachable();
+
+    // Lay the blocks out in the order control flows through them
+    assert!(body.move_before(exit).is_ok());
+    assert_eq!(function.get_basic_blocks(), [entry, body, exit, dead]);
+
+    assert!(dead.move_after(entry).is_ok());
+    assert_eq!(function.get_basic_blocks(), [entry, dead, body, exit]);
+
+    unsafe {
+        assert!(dead.delete().is_ok());
+    }
+
+    assert_eq!(function.get_basic_blocks(), [entry, body, exit]);
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+    let body_index = ir.find("body:").unwrap();
+    let exit_index = ir.find("exit:").unwrap();
+
+    assert!(body_index < exit_index, "{}", ir);
+    assert!(!ir.contains("dead:"), "{}", ir);
+}
+
 #[test]
 fn test_no_parent() {
     let context = Context::create();
@@ -1,5 +1,7 @@
 use inkwell::context::Context;
-use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, OptimizationLevel};
+use inkwell::types::BasicType;
+use inkwell::values::{AnyValue, BasicValue, BasicValueEnum, InstructionOpcode};
+use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, FloatPredicate, IntPredicate, OptimizationLevel};
 
 use std::convert::TryFrom;
 use std::ptr::null;
@@ -499,7 +501,7 @@ fn test_binary_ops() {
 
     // Here we're going to create a xor function which looks roughly like:
     // fn xor(left: bool, right: bool) -> bool {
-    //     left || right
+    //     left ^ right
     // }
 
     let fn_value = module.add_function("xor", fn_type, None);
@@ -598,6 +600,84 @@ fn test_switch() {
     }
 }
 
+#[test]
+fn test_switch_three_cases_jit() {
+    let context = Context::create();
+    let module = context.create_module("switch");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("lookup", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let blocks = [
+        context.append_basic_block(fn_value, "zero"),
+        context.append_basic_block(fn_value, "seven"),
+        context.append_basic_block(fn_value, "minus_one"),
+    ];
+    let default = context.append_basic_block(fn_value, "default");
+    let value = fn_value.get_first_param().unwrap().into_int_value();
+    let case_values = [
+        i32_type.const_int(0, false),
+        i32_type.const_int(7, false),
+        i32_type.const_int(-1i32 as u64, true),
+    ];
+
+    builder.position_at_end(entry);
+
+    let switch = builder.build_switch(
+        value,
+        default,
+        &[
+            (case_values[0], blocks[0]),
+            (case_values[1], blocks[1]),
+            (case_values[2], blocks[2]),
+        ],
+    );
+
+    assert_eq!(switch.get_opcode(), InstructionOpcode::Switch);
+
+    for (i, block) in blocks.iter().enumerate() {
+        builder.position_at_end(*block);
+        builder.build_return(Some(&i32_type.const_int(10 + i as u64, false)));
+    }
+
+    builder.position_at_end(default);
+    builder.build_return(Some(&i32_type.const_int(99, false)));
+
+    assert!(fn_value.verify(true));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let lookup = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("lookup")
+            .unwrap();
+
+        assert_eq!(lookup.call(0), 10);
+        assert_eq!(lookup.call(7), 11);
+        assert_eq!(lookup.call(-1), 12);
+        assert_eq!(lookup.call(1), 99);
+        assert_eq!(lookup.call(i32::MAX), 99);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Switch case values must have the type of the switched value")]
+fn test_switch_case_width_mismatch() {
+    let context = Context::create();
+    let module = context.create_module("switch");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = context.void_type().fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("switch", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let case = context.append_basic_block(fn_value, "case");
+    let value = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+    builder.build_switch(value, case, &[(context.i64_type().const_zero(), case)]);
+}
+
 #[test]
 fn test_bit_shifts() {
     let context = Context::create();
@@ -715,6 +795,181 @@ fn test_unconditional_branch() {
     builder.build_unreachable();
 }
 
+#[test]
+fn test_unreachable_switch_default() {
+    let context = Context::create();
+    let module = context.create_module("unreachable");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let fn_type = i8_type.fn_type(&[context.bool_type().into()], false);
+    let fn_value = module.add_function("to_byte", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let is_false = context.append_basic_block(fn_value, "is_false");
+    let is_true = context.append_basic_block(fn_value, "is_true");
+    let impossible = context.append_basic_block(fn_value, "impossible");
+    let value = fn_value.get_first_param().unwrap().into_int_value();
+    let bool_type = context.bool_type();
+
+    builder.position_at_end(entry);
+    builder.build_switch(
+        value,
+        impossible,
+        &[
+            (bool_type.const_zero(), is_false),
+            (bool_type.const_all_ones(), is_true),
+        ],
+    );
+
+    builder.position_at_end(is_false);
+    builder.build_return(Some(&i8_type.const_zero()));
+
+    builder.position_at_end(is_true);
+    builder.build_return(Some(&i8_type.const_int(1, false)));
+
+    builder.position_at_end(impossible);
+
+    let unreachable = builder.build_unreachable();
+
+    assert_eq!(unreachable.get_opcode(), InstructionOpcode::Unreachable);
+    assert_eq!(impossible.get_terminator(), Some(unreachable));
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("switch i1 %0, label %impossible"), "{}", ir);
+    assert!(ir.contains("\n  unreachable\n"), "{}", ir);
+}
+
+#[test]
+fn test_indirect_branch_jit() {
+    let context = Context::create();
+    let module = context.create_module("indirectbr");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[context.bool_type().into()], false);
+    let fn_value = module.add_function("computed_goto", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let first = context.append_basic_block(fn_value, "first");
+    let second = context.append_basic_block(fn_value, "second");
+    let cond = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let first_address = unsafe { first.get_address() }.unwrap();
+    let second_address = unsafe { second.get_address() }.unwrap();
+    let address = builder.build_select(cond, first_address, second_address, "address");
+    let indirect_branch = builder.build_indirect_branch(address, &[first, second]);
+
+    assert_eq!(indirect_branch.get_opcode(), InstructionOpcode::IndirectBr);
+
+    builder.position_at_end(first);
+    builder.build_return(Some(&i32_type.const_int(1, false)));
+
+    builder.position_at_end(second);
+    builder.build_return(Some(&i32_type.const_int(2, false)));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("blockaddress(@computed_goto, %first)"), "{}", ir);
+    assert!(ir.contains("[label %first, label %second]"), "{}", ir);
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let computed_goto = execution_engine
+            .get_function::<unsafe extern "C" fn(bool) -> i32>("computed_goto")
+            .unwrap();
+
+        assert_eq!(computed_goto.call(true), 1);
+        assert_eq!(computed_goto.call(false), 2);
+    }
+}
+
+#[test]
+fn test_select_max_jit() {
+    let context = Context::create();
+    let module = context.create_module("select");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("max", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let a = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let b = fn_value.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let a_is_greater = builder.build_int_compare(IntPredicate::SGT, a, b, "a_is_greater");
+    let max = builder.build_select(a_is_greater, a, b, "max");
+
+    assert_eq!(
+        max.as_instruction_value().unwrap().get_opcode(),
+        InstructionOpcode::Select
+    );
+
+    builder.build_return(Some(&max));
+
+    assert!(module.verify().is_ok());
+    assert!(module
+        .to_string()
+        .contains("%max = select i1 %a_is_greater, i32 %0, i32 %1"));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let max = execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32) -> i32>("max")
+            .unwrap();
+
+        assert_eq!(max.call(3, 7), 7);
+        assert_eq!(max.call(7, 3), 7);
+        assert_eq!(max.call(-5, -9), -5);
+    }
+}
+
+#[llvm_versions(10.0..=latest)]
+#[test]
+fn test_freeze_undef() {
+    let context = Context::create();
+    let module = context.create_module("freeze");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_value = module.add_function("frozen", i32_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let frozen = builder.build_freeze(i32_type.get_undef(), "frozen");
+
+    assert_eq!(frozen.get_type(), i32_type.into());
+    assert_eq!(
+        frozen.as_instruction_value().unwrap().get_opcode(),
+        InstructionOpcode::Freeze
+    );
+
+    builder.build_return(Some(&frozen));
+
+    assert!(module.verify().is_ok());
+    assert!(module.to_string().contains("%frozen = freeze i32 undef"));
+}
+
+#[test]
+#[should_panic(expected = "Select operands must have the same type")]
+fn test_select_operand_type_mismatch() {
+    let context = Context::create();
+    let module = context.create_module("select");
+    let builder = context.create_builder();
+    let fn_value = module.add_function("select", context.void_type().fn_type(&[], false), None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let then: BasicValueEnum = context.i32_type().const_zero().into();
+    let else_: BasicValueEnum = context.i64_type().const_zero().into();
+
+    builder.position_at_end(entry);
+    builder.build_select(context.bool_type().const_zero(), then, else_, "select");
+}
+
 #[test]
 fn test_no_builder_double_free() {
     let context = Context::create();
@@ -920,6 +1175,152 @@ fn test_vector_pointer_ops() {
     assert!(fn_value.verify(true));
 }
 
+#[test]
+fn test_struct_insert_extract_value_jit() {
+    let context = Context::create();
+    let module = context.create_module("tuples");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let pair_type = context.struct_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("second", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let first = fn_value.get_nth_param(0).unwrap();
+    let second = fn_value.get_nth_param(1).unwrap();
+
+    builder.position_at_end(entry);
+
+    let pair = builder
+        .build_insert_value(pair_type.get_undef(), first, 0, "pair")
+        .unwrap();
+    let pair = builder
+        .build_insert_value(pair, second, 1, "pair")
+        .unwrap()
+        .into_struct_value();
+
+    assert_eq!(pair.get_type(), pair_type);
+    assert!(builder.build_extract_value(pair, 2, "out_of_bounds").is_none());
+
+    let extracted = builder.build_extract_value(pair, 1, "extracted").unwrap();
+
+    builder.build_return(Some(&extracted));
+
+    assert!(module.verify().is_ok());
+    assert!(module
+        .to_string()
+        .contains("%extracted = extractvalue { i32, i32 } %pair1, 1"));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let second = execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32) -> i32>("second")
+            .unwrap();
+
+        assert_eq!(second.call(1, 2), 2);
+        assert_eq!(second.call(-8, 13), 13);
+    }
+}
+
+#[test]
+fn test_struct_from_undef_jit() {
+    #[repr(C)]
+    #[derive(Debug, PartialEq)]
+    struct Pair {
+        a: i32,
+        b: i64,
+    }
+
+    let context = Context::create();
+    let module = context.create_module("seeded");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let pair_type = context.struct_type(&[i32_type.into(), i64_type.into()], false);
+    let pair_ptr_type = pair_type.ptr_type(AddressSpace::default());
+    let fn_type = context
+        .void_type()
+        .fn_type(&[pair_ptr_type.into(), i32_type.into(), i64_type.into()], false);
+    let fn_value = module.add_function("make_pair", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let out = fn_value.get_nth_param(0).unwrap().into_pointer_value();
+    let a = fn_value.get_nth_param(1).unwrap();
+    let b = fn_value.get_nth_param(2).unwrap();
+
+    builder.position_at_end(entry);
+
+    let undef = pair_type.as_basic_type_enum().get_undef();
+
+    assert!(undef.into_struct_value().is_undef());
+
+    let pair = builder
+        .build_insert_value(undef.into_struct_value(), a, 0, "pair")
+        .unwrap();
+    let pair = builder.build_insert_value(pair, b, 1, "pair").unwrap();
+
+    builder.build_store(out, pair);
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let make_pair = execution_engine
+            .get_function::<unsafe extern "C" fn(*mut Pair, i32, i64)>("make_pair")
+            .unwrap();
+        let mut pair = Pair { a: 0, b: 0 };
+
+        make_pair.call(&mut pair, -7, 1 << 40);
+
+        assert_eq!(pair, Pair { a: -7, b: 1 << 40 });
+    }
+}
+
+#[test]
+fn test_malloc_store_load_free_jit() {
+    let context = Context::create();
+    let module = context.create_module("heap");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("round_trip", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let value = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let ptr = builder.build_malloc(i32_type, "ptr").unwrap();
+
+    builder.build_store(ptr, value);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let loaded = builder.build_load(ptr, "loaded");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let loaded = builder.build_load(i32_type, ptr, "loaded");
+
+    let free = builder.build_free(ptr);
+
+    assert_eq!(free.get_opcode(), InstructionOpcode::Call);
+
+    builder.build_return(Some(&loaded));
+
+    assert!(module.get_function("malloc").is_some());
+    assert!(module.get_function("free").is_some());
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let round_trip = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("round_trip")
+            .unwrap();
+
+        assert_eq!(round_trip.call(42), 42);
+        assert_eq!(round_trip.call(-1), -1);
+    }
+}
+
 #[test]
 fn test_insert_value() {
     let context = Context::create();
@@ -1337,7 +1738,7 @@ fn run_memset_on<'ctx>(
 #[test]
 fn test_memset() {
     // 1. Allocate an array with a few elements.
-    // 2. Memmove from the first half of the array to the second half.
+    // 2. Memset the first half of the array to zero.
     // 3. Run the code in an execution engine and verify the array's contents.
     let context = Context::create();
     let module = context.create_module("av");
@@ -1361,6 +1762,61 @@ fn test_memset() {
     }
 }
 
+#[llvm_versions(8.0..=latest)]
+#[test]
+fn test_memset_stack_buffer_jit() {
+    let context = Context::create();
+    let module = context.create_module("memset");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let i64_type = context.i64_type();
+    let i8_ptr_type = i8_type.ptr_type(AddressSpace::default());
+    let fn_type = context.void_type().fn_type(&[i8_ptr_type.into()], false);
+    let fn_value = module.add_function("fill", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let out = fn_value.get_first_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(entry);
+
+    let buffer = builder.build_array_alloca(i8_type, i64_type.const_int(8, false), "buffer");
+    let all_ones = i8_type.const_all_ones();
+
+    assert!(builder
+        .build_memset(buffer, 3, all_ones, i64_type.const_int(8, false))
+        .is_err());
+
+    builder
+        .build_memset(buffer, 1, all_ones, i64_type.const_int(8, false))
+        .unwrap();
+    builder
+        .build_memset(buffer, 1, i8_type.const_zero(), i64_type.const_int(5, false))
+        .unwrap();
+    builder
+        .build_memcpy(out, 1, buffer, 1, i64_type.const_int(8, false))
+        .unwrap();
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("call void @llvm.memset."), "{}", ir);
+    assert!(ir.contains("call void @llvm.memcpy."), "{}", ir);
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let fill = execution_engine
+            .get_function::<unsafe extern "C" fn(*mut u8)>("fill")
+            .unwrap();
+        let mut bytes = [7u8; 8];
+
+        fill.call(bytes.as_mut_ptr());
+
+        assert_eq!(bytes, [0, 0, 0, 0, 0, 0xff, 0xff, 0xff]);
+    }
+}
+
 #[test]
 fn test_bitcast() {
     use inkwell::values::BasicValue;
@@ -1413,28 +1869,413 @@ fn test_bitcast() {
 }
 
 #[test]
-fn test_atomicrmw() {
+fn test_position_before_return_jit() {
     let context = Context::create();
-    let module = context.create_module("rmw");
-
-    let void_type = context.void_type();
-    let fn_type = void_type.fn_type(&[], false);
-    let fn_value = module.add_function("", fn_type, None);
-    let entry = context.append_basic_block(fn_value, "entry");
+    let module = context.create_module("instrument");
     let builder = context.create_builder();
-    builder.position_at_end(entry);
-
     let i32_type = context.i32_type();
-    let i64_type = context.i64_type();
-    let i31_type = context.custom_width_int_type(31);
-    let i4_type = context.custom_width_int_type(4);
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("f", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let x = fn_value.get_first_param().unwrap().into_int_value();
 
-    let ptr_value = i32_type.ptr_type(AddressSpace::default()).get_undef();
-    let zero_value = i32_type.const_zero();
-    let result = builder.build_atomicrmw(AtomicRMWBinOp::Add, ptr_value, zero_value, AtomicOrdering::Unordered);
-    assert!(result.is_ok());
+    assert!(builder.get_insert_block().is_none());
 
-    #[cfg(any(
+    builder.position_at_end(entry);
+
+    let ret = builder.build_return(Some(&x));
+
+    // Instrument the existing return with (x * 3) + x
+    builder.position_before(&ret);
+
+    let sum = builder.build_int_add(x, x, "sum");
+    let sum_instruction = sum.as_instruction_value().unwrap();
+
+    builder.position_at(entry, &sum_instruction);
+
+    let tripled = builder.build_int_mul(x, i32_type.const_int(3, false), "tripled");
+
+    assert!(sum_instruction.set_operand(0, tripled));
+    assert!(ret.set_operand(0, sum));
+    assert_eq!(builder.get_insert_block(), Some(entry));
+
+    let opcodes: Vec<_> = entry
+        .get_instructions()
+        .map(|instruction| instruction.get_opcode())
+        .collect();
+
+    assert_eq!(
+        opcodes,
+        [
+            InstructionOpcode::Mul,
+            InstructionOpcode::Add,
+            InstructionOpcode::Return
+        ]
+    );
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let f = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("f")
+            .unwrap();
+
+        assert_eq!(f.call(5), 20);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Instruction must belong to the given basic block")]
+fn test_position_at_instruction_from_other_block() {
+    let context = Context::create();
+    let module = context.create_module("instrument");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("f", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let exit = context.append_basic_block(fn_value, "exit");
+
+    builder.position_at_end(entry);
+    builder.build_unconditional_branch(exit);
+    builder.position_at_end(exit);
+
+    let ret = builder.build_return(None);
+
+    builder.position_at(entry, &ret);
+}
+
+#[test]
+fn test_clear_insertion_position_and_drop_many_builders() {
+    let context = Context::create();
+    let module = context.create_module("builders");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("f", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    // Each builder is disposed of when it is dropped at the end of the iteration
+    for _ in 0..10_000 {
+        let builder = context.create_builder();
+
+        builder.position_at_end(entry);
+
+        assert_eq!(builder.get_insert_block(), Some(entry));
+
+        builder.clear_insertion_position();
+
+        assert!(builder.get_insert_block().is_none());
+    }
+
+    let builder = context.create_builder();
+
+    builder.position_at_end(entry);
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_no_wrap_int_arithmetic_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("no_wrap");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("f", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let x = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let y = fn_value.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let values = [
+        builder.build_int_nsw_add(x, y, "nsw_add"),
+        builder.build_int_nuw_add(x, y, "nuw_add"),
+        builder.build_int_nsw_sub(x, y, "nsw_sub"),
+        builder.build_int_nuw_sub(x, y, "nuw_sub"),
+        builder.build_int_nsw_mul(x, y, "nsw_mul"),
+        builder.build_int_nuw_mul(x, y, "nuw_mul"),
+    ];
+    let sum = values.iter().fold(i32_type.const_zero(), |acc, value| {
+        builder.build_int_add(acc, *value, "sum")
+    });
+
+    builder.build_return(Some(&sum));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    for expected in [
+        "%nsw_add = add nsw i32 %0, %1",
+        "%nuw_add = add nuw i32 %0, %1",
+        "%nsw_sub = sub nsw i32 %0, %1",
+        "%nuw_sub = sub nuw i32 %0, %1",
+        "%nsw_mul = mul nsw i32 %0, %1",
+        "%nuw_mul = mul nuw i32 %0, %1",
+    ] {
+        assert!(ir.contains(expected), "{}", ir);
+    }
+
+    // The plain builders never set either flag
+    assert!(!ir.contains("%sum = add nsw"), "{}", ir);
+    assert!(!ir.contains("%sum = add nuw"), "{}", ir);
+}
+
+#[test]
+fn test_unsafe_fp_math_function_attributes_in_ir() {
+    use inkwell::attributes::AttributeLoc;
+
+    let context = Context::create();
+    let module = context.create_module("fp_math");
+    let builder = context.create_builder();
+    let f64_type = context.f64_type();
+    let fn_type = f64_type.fn_type(&[f64_type.into(), f64_type.into()], false);
+    let fn_value = module.add_function("fmul", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let x = fn_value.get_nth_param(0).unwrap().into_float_value();
+    let y = fn_value.get_nth_param(1).unwrap().into_float_value();
+
+    // Fast-math flags can't be set per instruction before LLVM 18, so relax the whole function
+    for key in [
+        "unsafe-fp-math",
+        "no-nans-fp-math",
+        "no-infs-fp-math",
+        "no-signed-zeros-fp-math",
+    ] {
+        fn_value.add_attribute(AttributeLoc::Function, context.create_string_attribute(key, "true"));
+    }
+
+    builder.position_at_end(entry);
+
+    let product = builder.build_float_mul(x, y, "product");
+
+    builder.build_return(Some(&product));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("%product = fmul double %0, %1"), "{}", ir);
+    assert!(ir.contains("\"unsafe-fp-math\"=\"true\""), "{}", ir);
+    assert!(ir.contains("\"no-nans-fp-math\"=\"true\""), "{}", ir);
+}
+
+#[test]
+fn test_bitwise_mask_jit() {
+    let context = Context::create();
+    let module = context.create_module("bitwise");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+
+    // fn low_byte(x: u32) -> u32 { x & 0xFF }
+    let low_byte = module.add_function("low_byte", fn_type, None);
+    let entry = context.append_basic_block(low_byte, "entry");
+    let x = low_byte.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let masked = builder.build_and(x, i32_type.const_int(0xFF, false), "masked");
+
+    builder.build_return(Some(&masked));
+
+    // fn clear_low_byte(x: u32) -> u32 { (x | 0xFF) ^ 0xFF }, checked against x & !0xFF
+    let clear_low_byte = module.add_function("clear_low_byte", fn_type, None);
+    let entry = context.append_basic_block(clear_low_byte, "entry");
+    let x = clear_low_byte.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let byte = i32_type.const_int(0xFF, false);
+    let set = builder.build_or(x, byte, "set");
+    let cleared = builder.build_xor(set, byte, "cleared");
+
+    builder.build_return(Some(&cleared));
+
+    // fn invert(x: u32) -> u32 { !x }
+    let invert = module.add_function("invert", fn_type, None);
+    let entry = context.append_basic_block(invert, "entry");
+    let x = invert.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let inverted = builder.build_not(x, "inverted");
+
+    builder.build_return(Some(&inverted));
+
+    assert!(module.verify().is_ok());
+    assert!(module.to_string().contains("%inverted = xor i32 %0, -1"));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        type U32Func = unsafe extern "C" fn(u32) -> u32;
+
+        let low_byte = execution_engine.get_function::<U32Func>("low_byte").unwrap();
+        let clear_low_byte = execution_engine.get_function::<U32Func>("clear_low_byte").unwrap();
+        let invert = execution_engine.get_function::<U32Func>("invert").unwrap();
+
+        for x in [0, 0xFF, 0x1234_5678, u32::MAX] {
+            assert_eq!(low_byte.call(x), x & 0xFF);
+            assert_eq!(clear_low_byte.call(x), x & !0xFF);
+            assert_eq!(invert.call(x), !x);
+        }
+    }
+}
+
+#[test]
+fn test_negation_jit() {
+    let context = Context::create();
+    let module = context.create_module("negation");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+
+    let int_neg = module.add_function("int_neg", i32_type.fn_type(&[i32_type.into()], false), None);
+    let entry = context.append_basic_block(int_neg, "entry");
+    let x = int_neg.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let negated = builder.build_int_neg(x, "negated");
+
+    // Only checked in the IR, as they produce poison on overflow
+    builder.build_int_nsw_neg(x, "nsw_negated");
+    builder.build_int_nuw_neg(x, "nuw_negated");
+    builder.build_return(Some(&negated));
+
+    let float_neg = module.add_function("float_neg", f64_type.fn_type(&[f64_type.into()], false), None);
+    let entry = context.append_basic_block(float_neg, "entry");
+    let y = float_neg.get_first_param().unwrap().into_float_value();
+
+    builder.position_at_end(entry);
+
+    let negated = builder.build_float_neg(y, "negated");
+
+    builder.build_return(Some(&negated));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("%negated = sub i32 0, %0"), "{}", ir);
+    assert!(ir.contains("%nsw_negated = sub nsw i32 0, %0"), "{}", ir);
+    assert!(ir.contains("%nuw_negated = sub nuw i32 0, %0"), "{}", ir);
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let int_neg = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("int_neg")
+            .unwrap();
+        let float_neg = execution_engine
+            .get_function::<unsafe extern "C" fn(f64) -> f64>("float_neg")
+            .unwrap();
+
+        assert_eq!(int_neg.call(42), -42);
+        assert_eq!(int_neg.call(-7), 7);
+        assert_eq!(int_neg.call(0), 0);
+        assert_eq!(int_neg.call(i32::MIN), i32::MIN);
+        assert_eq!(float_neg.call(1.5), -1.5);
+        assert_eq!(float_neg.call(-2.25), 2.25);
+        assert!(float_neg.call(0.0).is_sign_negative());
+    }
+}
+
+#[test]
+fn test_remainder_jit() {
+    let context = Context::create();
+    let module = context.create_module("remainder");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+    let int_fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+
+    let signed_rem = module.add_function("signed_rem", int_fn_type, None);
+    let entry = context.append_basic_block(signed_rem, "entry");
+    let lhs = signed_rem.get_nth_param(0).unwrap().into_int_value();
+    let rhs = signed_rem.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let rem = builder.build_int_signed_rem(lhs, rhs, "rem");
+
+    builder.build_return(Some(&rem));
+
+    let unsigned_rem = module.add_function("unsigned_rem", int_fn_type, None);
+    let entry = context.append_basic_block(unsigned_rem, "entry");
+    let lhs = unsigned_rem.get_nth_param(0).unwrap().into_int_value();
+    let rhs = unsigned_rem.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let rem = builder.build_int_unsigned_rem(lhs, rhs, "rem");
+
+    builder.build_return(Some(&rem));
+
+    let float_fn_type = f64_type.fn_type(&[f64_type.into(), f64_type.into()], false);
+    let float_rem = module.add_function("float_rem", float_fn_type, None);
+    let entry = context.append_basic_block(float_rem, "entry");
+    let lhs = float_rem.get_nth_param(0).unwrap().into_float_value();
+    let rhs = float_rem.get_nth_param(1).unwrap().into_float_value();
+
+    builder.position_at_end(entry);
+
+    let rem = builder.build_float_rem(lhs, rhs, "rem");
+
+    builder.build_return(Some(&rem));
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let signed_rem = execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32) -> i32>("signed_rem")
+            .unwrap();
+        let unsigned_rem = execution_engine
+            .get_function::<unsafe extern "C" fn(u32, u32) -> u32>("unsigned_rem")
+            .unwrap();
+        let float_rem = execution_engine
+            .get_function::<unsafe extern "C" fn(f64, f64) -> f64>("float_rem")
+            .unwrap();
+
+        for (lhs, rhs) in [(7, 3), (-7, 3), (7, -3), (-7, -3), (0, 5), (i32::MIN, 7)] {
+            assert_eq!(signed_rem.call(lhs, rhs), lhs % rhs, "{} % {}", lhs, rhs);
+        }
+
+        // -7 as u32 is 4294967289, so the remainder differs from the signed one
+        assert_eq!(unsigned_rem.call(-7i32 as u32, 10), 9);
+        assert_eq!(unsigned_rem.call(10, 4), 2);
+        assert_eq!(float_rem.call(7.5, 2.0), 1.5);
+        assert_eq!(float_rem.call(-7.5, 2.0), -1.5);
+    }
+}
+
+#[test]
+fn test_atomicrmw() {
+    let context = Context::create();
+    let module = context.create_module("rmw");
+
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let builder = context.create_builder();
+    builder.position_at_end(entry);
+
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let i31_type = context.custom_width_int_type(31);
+    let i4_type = context.custom_width_int_type(4);
+
+    let ptr_value = i32_type.ptr_type(AddressSpace::default()).get_undef();
+    let zero_value = i32_type.const_zero();
+    let result = builder.build_atomicrmw(AtomicRMWBinOp::Add, ptr_value, zero_value, AtomicOrdering::Unordered);
+    assert!(result.is_ok());
+
+    #[cfg(any(
         feature = "llvm4-0",
         feature = "llvm5-0",
         feature = "llvm6-0",
@@ -1465,6 +2306,114 @@ fn test_atomicrmw() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_atomic_increment_jit() {
+    let context = Context::create();
+    let module = context.create_module("atomics");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::default());
+
+    let fn_type = i32_type.fn_type(&[i32_ptr_type.into()], false);
+    let increment = module.add_function("increment", fn_type, None);
+    let entry = context.append_basic_block(increment, "entry");
+    let counter = increment.get_first_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(entry);
+
+    let one = i32_type.const_int(1, false);
+    let previous = builder
+        .build_atomicrmw(
+            AtomicRMWBinOp::Add,
+            counter,
+            one,
+            AtomicOrdering::SequentiallyConsistent,
+        )
+        .unwrap();
+
+    builder.build_return(Some(&previous));
+
+    let fn_type = context
+        .bool_type()
+        .fn_type(&[i32_ptr_type.into(), i32_type.into(), i32_type.into()], false);
+    let compare_exchange = module.add_function("compare_exchange", fn_type, None);
+    let entry = context.append_basic_block(compare_exchange, "entry");
+    let counter = compare_exchange.get_nth_param(0).unwrap().into_pointer_value();
+    let expected = compare_exchange.get_nth_param(1).unwrap().into_int_value();
+    let new = compare_exchange.get_nth_param(2).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let pair = builder
+        .build_cmpxchg(
+            counter,
+            expected,
+            new,
+            AtomicOrdering::AcquireRelease,
+            AtomicOrdering::Monotonic,
+        )
+        .unwrap();
+    let success = builder.build_extract_value(pair, 1, "success").unwrap();
+
+    builder.build_return(Some(&success));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("atomicrmw add"), "{}", ir);
+    assert!(ir.contains("seq_cst"), "{}", ir);
+    assert!(ir.contains("cmpxchg"), "{}", ir);
+    assert!(ir.contains("acq_rel monotonic"), "{}", ir);
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let increment = execution_engine
+            .get_function::<unsafe extern "C" fn(*mut i32) -> i32>("increment")
+            .unwrap();
+        let compare_exchange = execution_engine
+            .get_function::<unsafe extern "C" fn(*mut i32, i32, i32) -> bool>("compare_exchange")
+            .unwrap();
+        let mut counter = 41;
+
+        assert_eq!(increment.call(&mut counter), 41);
+        assert_eq!(counter, 42);
+        assert_eq!(increment.call(&mut counter), 42);
+        assert_eq!(counter, 43);
+
+        assert!(!compare_exchange.call(&mut counter, 0, 7));
+        assert_eq!(counter, 43);
+        assert!(compare_exchange.call(&mut counter, 43, 7));
+        assert_eq!(counter, 7);
+    }
+}
+
+#[test]
+fn test_fence_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("fence");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let function = module.add_function("fences", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+
+    builder.position_at_end(entry);
+
+    let fence = builder.build_fence(AtomicOrdering::SequentiallyConsistent, 0, "");
+
+    builder.build_fence(AtomicOrdering::Acquire, 1, "");
+    builder.build_return(None);
+
+    assert_eq!(fence.get_opcode(), InstructionOpcode::Fence);
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("fence seq_cst"), "{}", ir);
+    assert!(ir.contains("fence syncscope(\"singlethread\") acquire"), "{}", ir);
+}
+
 #[test]
 fn test_cmpxchg() {
     let context = Context::create();
@@ -1696,3 +2645,624 @@ fn test_safe_struct_gep() {
             .is_err());
     }
 }
+
+#[test]
+fn test_build_ret_void_function() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("f", fn_type, None);
+
+    assert!(builder.get_insert_block().is_none());
+
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    assert!(entry.get_terminator().is_none());
+
+    builder.position_at_end(entry);
+
+    let ret = builder.build_return(None);
+
+    assert_eq!(builder.get_insert_block(), Some(entry));
+    assert_eq!(entry.get_terminator(), Some(ret));
+    assert!(fn_value.verify(false));
+    assert_eq!(
+        fn_value.print_to_string().to_string(),
+        "define void @f() {\nentry:\n  ret void\n}\n"
+    );
+}
+
+#[test]
+fn test_int_and_float_arithmetic() {
+    use inkwell::builder::Builder;
+    use inkwell::values::{FloatValue, IntValue};
+
+    let context = Context::create();
+    let module = context.create_module("arithmetic");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+    let int_fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let float_fn_type = f64_type.fn_type(&[f64_type.into(), f64_type.into()], false);
+
+    type IntOp<'ctx> = fn(&Builder<'ctx>, IntValue<'ctx>, IntValue<'ctx>, &str) -> IntValue<'ctx>;
+    type FloatOp<'ctx> = fn(&Builder<'ctx>, FloatValue<'ctx>, FloatValue<'ctx>, &str) -> FloatValue<'ctx>;
+
+    let int_ops: [(&str, IntOp); 5] = [
+        ("add", Builder::build_int_add),
+        ("sub", Builder::build_int_sub),
+        ("mul", Builder::build_int_mul),
+        ("sdiv", Builder::build_int_signed_div),
+        ("udiv", Builder::build_int_unsigned_div),
+    ];
+    let float_ops: [(&str, FloatOp); 4] = [
+        ("fadd", Builder::build_float_add),
+        ("fsub", Builder::build_float_sub),
+        ("fmul", Builder::build_float_mul),
+        ("fdiv", Builder::build_float_div),
+    ];
+
+    for (name, op) in int_ops {
+        let fn_value = module.add_function(name, int_fn_type, None);
+        let entry = context.append_basic_block(fn_value, "entry");
+
+        builder.position_at_end(entry);
+
+        let lhs = fn_value.get_nth_param(0).unwrap().into_int_value();
+        let rhs = fn_value.get_nth_param(1).unwrap().into_int_value();
+        let result = op(&builder, lhs, rhs, name);
+
+        assert_eq!(result.get_name().to_str(), Ok(name));
+
+        builder.build_return(Some(&result));
+    }
+
+    for (name, op) in float_ops {
+        let fn_value = module.add_function(name, float_fn_type, None);
+        let entry = context.append_basic_block(fn_value, "entry");
+
+        builder.position_at_end(entry);
+
+        let lhs = fn_value.get_nth_param(0).unwrap().into_float_value();
+        let rhs = fn_value.get_nth_param(1).unwrap().into_float_value();
+        let result = op(&builder, lhs, rhs, name);
+
+        builder.build_return(Some(&result));
+    }
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    type IntFunc = unsafe extern "C" fn(i32, i32) -> i32;
+    type FloatFunc = unsafe extern "C" fn(f64, f64) -> f64;
+
+    unsafe {
+        let call_int = |name| execution_engine.get_function::<IntFunc>(name).unwrap();
+        let call_float = |name| execution_engine.get_function::<FloatFunc>(name).unwrap();
+
+        assert_eq!(call_int("add").call(2, 3), 5);
+        assert_eq!(call_int("add").call(i32::MAX, 1), i32::MIN);
+        assert_eq!(call_int("sub").call(2, 3), -1);
+        assert_eq!(call_int("mul").call(-4, 3), -12);
+        assert_eq!(call_int("sdiv").call(-7, 2), -3);
+        assert_eq!(call_int("udiv").call(-2, 2), i32::MAX);
+
+        assert_eq!(call_float("fadd").call(1.5, 2.25), 3.75);
+        assert_eq!(call_float("fsub").call(1.5, 2.25), -0.75);
+        assert_eq!(call_float("fmul").call(1.5, -2.0), -3.0);
+        assert_eq!(call_float("fdiv").call(1.0, 4.0), 0.25);
+    }
+}
+
+#[test]
+fn test_alloca_store_load_round_trip() {
+    let context = Context::create();
+    let module = context.create_module("stack");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("round_trip", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let slot = builder.build_alloca(i32_type, "slot");
+
+    assert_eq!(slot.get_name().to_str(), Ok("slot"));
+    assert_eq!(slot.get_type(), i32_type.ptr_type(AddressSpace::default()));
+
+    let store = builder.build_store(slot, i32_type.const_int(7, false));
+
+    assert_eq!(store.get_opcode(), InstructionOpcode::Store);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let seven = builder.build_load(slot, "seven").into_int_value();
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let seven = builder.build_load(i32_type, slot, "seven").into_int_value();
+
+    let param = fn_value.get_first_param().unwrap().into_int_value();
+    let sum = builder.build_int_add(seven, param, "sum");
+
+    builder.build_store(slot, sum);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let result = builder.build_load(slot, "result");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let result = builder.build_load(i32_type, slot, "result");
+
+    builder.build_return(Some(&result));
+
+    assert!(fn_value.verify(false));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let round_trip = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("round_trip")
+            .unwrap();
+
+        assert_eq!(round_trip.call(0), 7);
+        assert_eq!(round_trip.call(35), 42);
+    }
+}
+
+#[test]
+fn test_build_call_forwards_result() {
+    let context = Context::create();
+    let module = context.create_module("calls");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+
+    // fn double(x: i32) -> i32 { x * 2 }
+    let double_fn = module.add_function("double", i32_type.fn_type(&[i32_type.into()], false), None);
+    let entry = context.append_basic_block(double_fn, "entry");
+
+    builder.position_at_end(entry);
+
+    let x = double_fn.get_first_param().unwrap().into_int_value();
+    let doubled = builder.build_int_mul(x, i32_type.const_int(2, false), "doubled");
+
+    builder.build_return(Some(&doubled));
+
+    // fn nothing() {}
+    let nothing_fn = module.add_function("nothing", void_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(nothing_fn, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(None);
+
+    // fn caller(x: i32) -> i32 { nothing(); double(x) }
+    let caller_fn = module.add_function("caller", i32_type.fn_type(&[i32_type.into()], false), None);
+    let entry = context.append_basic_block(caller_fn, "entry");
+
+    builder.position_at_end(entry);
+
+    let void_call = builder.build_call(nothing_fn, &[], "ignored");
+
+    assert!(void_call.try_as_basic_value().is_right());
+
+    let x = caller_fn.get_first_param().unwrap();
+    let call = builder.build_call(double_fn, &[x.into()], "call");
+
+    assert_eq!(call.get_called_fn_value(), double_fn);
+
+    let result = call.try_as_basic_value().left().unwrap();
+
+    builder.build_return(Some(&result));
+
+    assert!(module.verify().is_ok());
+    assert!(module.to_string().contains("  call void @nothing()\n"));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let caller = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("caller")
+            .unwrap();
+
+        assert_eq!(caller.call(21), 42);
+        assert_eq!(caller.call(-3), -6);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Expected 1 arguments for call, found 0")]
+fn test_build_call_wrong_arg_count() {
+    let context = Context::create();
+    let module = context.create_module("calls");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_value = module.add_function("callee", i32_type.fn_type(&[i32_type.into()], false), None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_call(fn_value, &[], "call");
+}
+
+#[test]
+fn test_branches_abs() {
+    let context = Context::create();
+    let module = context.create_module("branches");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("abs", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let negative = context.append_basic_block(fn_value, "negative");
+    let positive = context.append_basic_block(fn_value, "positive");
+    let exit = context.append_basic_block(fn_value, "exit");
+    let x = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let is_negative = builder.build_int_compare(IntPredicate::SLT, x, i32_type.const_zero(), "is_negative");
+    let cond_br = builder.build_conditional_branch(is_negative, negative, positive);
+
+    assert_eq!(cond_br.get_opcode(), InstructionOpcode::Br);
+    assert_eq!(cond_br.get_num_operands(), 3);
+    assert_eq!(entry.get_terminator(), Some(cond_br));
+
+    builder.position_at_end(negative);
+
+    let neg = builder.build_int_neg(x, "neg");
+
+    builder.build_return(Some(&neg));
+    builder.position_at_end(positive);
+
+    let br = builder.build_unconditional_branch(exit);
+
+    assert_eq!(br.get_opcode(), InstructionOpcode::Br);
+    assert_eq!(br.get_num_operands(), 1);
+
+    builder.position_at_end(exit);
+    builder.build_return(Some(&x));
+
+    assert!(fn_value.verify(false));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let abs = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("abs")
+            .unwrap();
+
+        assert_eq!(abs.call(-5), 5);
+        assert_eq!(abs.call(5), 5);
+        assert_eq!(abs.call(0), 0);
+        assert_eq!(abs.call(i32::MIN + 1), i32::MAX);
+    }
+}
+
+#[test]
+fn test_int_and_float_compare() {
+    let context = Context::create();
+    let module = context.create_module("compare");
+    let builder = context.create_builder();
+    let bool_type = context.bool_type();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+
+    let int_predicates = [
+        ("is_greater", IntPredicate::SGT),
+        ("is_unsigned_greater", IntPredicate::UGT),
+        ("is_equal", IntPredicate::EQ),
+    ];
+
+    for (name, predicate) in int_predicates {
+        let fn_type = bool_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+        let fn_value = module.add_function(name, fn_type, None);
+        let entry = context.append_basic_block(fn_value, "entry");
+        let lhs = fn_value.get_nth_param(0).unwrap().into_int_value();
+        let rhs = fn_value.get_nth_param(1).unwrap().into_int_value();
+
+        builder.position_at_end(entry);
+
+        let cmp = builder.build_int_compare(predicate, lhs, rhs, name);
+
+        assert_eq!(cmp.get_type(), bool_type);
+        assert_eq!(cmp.as_instruction().unwrap().get_icmp_predicate(), Some(predicate));
+
+        builder.build_return(Some(&cmp));
+    }
+
+    let float_predicates = [
+        ("is_less", FloatPredicate::OLT),
+        ("is_unordered_less", FloatPredicate::ULT),
+    ];
+
+    for (name, predicate) in float_predicates {
+        let fn_type = bool_type.fn_type(&[f64_type.into(), f64_type.into()], false);
+        let fn_value = module.add_function(name, fn_type, None);
+        let entry = context.append_basic_block(fn_value, "entry");
+        let lhs = fn_value.get_nth_param(0).unwrap().into_float_value();
+        let rhs = fn_value.get_nth_param(1).unwrap().into_float_value();
+
+        builder.position_at_end(entry);
+
+        let cmp = builder.build_float_compare(predicate, lhs, rhs, name);
+
+        assert_eq!(cmp.get_type(), bool_type);
+        assert_eq!(cmp.as_instruction().unwrap().get_fcmp_predicate(), Some(predicate));
+
+        builder.build_return(Some(&cmp));
+    }
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        type IntCmp = unsafe extern "C" fn(i32, i32) -> bool;
+        type FloatCmp = unsafe extern "C" fn(f64, f64) -> bool;
+
+        let is_greater = execution_engine.get_function::<IntCmp>("is_greater").unwrap();
+        let is_unsigned_greater = execution_engine.get_function::<IntCmp>("is_unsigned_greater").unwrap();
+        let is_equal = execution_engine.get_function::<IntCmp>("is_equal").unwrap();
+        let is_less = execution_engine.get_function::<FloatCmp>("is_less").unwrap();
+        let is_unordered_less = execution_engine.get_function::<FloatCmp>("is_unordered_less").unwrap();
+
+        assert!(is_greater.call(3, 2));
+        assert!(!is_greater.call(2, 3));
+        assert!(!is_greater.call(2, 2));
+        assert!(!is_greater.call(-1, 1));
+        assert!(is_unsigned_greater.call(-1, 1));
+        assert!(is_equal.call(7, 7));
+        assert!(!is_equal.call(7, -7));
+
+        assert!(is_less.call(1.0, 2.0));
+        assert!(!is_less.call(2.0, 1.0));
+        assert!(!is_less.call(f64::NAN, 1.0));
+        assert!(is_unordered_less.call(f64::NAN, 1.0));
+    }
+}
+
+#[test]
+fn test_phi_counting_loop() {
+    let context = Context::create();
+    let module = context.create_module("phi");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("count_to", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let loop_block = context.append_basic_block(fn_value, "loop");
+    let exit = context.append_basic_block(fn_value, "exit");
+    let n = fn_value.get_first_param().unwrap().into_int_value();
+    let zero = i32_type.const_zero();
+    let one = i32_type.const_int(1, false);
+
+    // fn count_to(n: i32) -> i32 { let mut i = 0; loop { i += 1; if i >= n { return i } } }
+    builder.position_at_end(entry);
+    builder.build_unconditional_branch(loop_block);
+    builder.position_at_end(loop_block);
+
+    let i = builder.build_phi(i32_type, "i");
+
+    assert_eq!(i.count_incoming(), 0);
+    assert!(i.get_incoming(0).is_none());
+    assert_eq!(i.as_instruction().get_opcode(), InstructionOpcode::Phi);
+    assert_eq!(loop_block.get_first_instruction(), Some(i.as_instruction()));
+
+    let next = builder.build_int_add(i.as_basic_value().into_int_value(), one, "next");
+    let done = builder.build_int_compare(IntPredicate::SGE, next, n, "done");
+
+    builder.build_conditional_branch(done, exit, loop_block);
+
+    i.add_incoming(&[(&zero, entry), (&next, loop_block)]);
+
+    assert_eq!(i.count_incoming(), 2);
+    assert_eq!(i.get_incoming(0), Some((zero.into(), entry)));
+    assert_eq!(i.get_incoming(1), Some((next.into(), loop_block)));
+    assert!(i.get_incoming(2).is_none());
+
+    builder.position_at_end(exit);
+    builder.build_return(Some(&next));
+
+    assert!(fn_value.verify(false));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let count_to = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("count_to")
+            .unwrap();
+
+        assert_eq!(count_to.call(10), 10);
+        assert_eq!(count_to.call(1), 1);
+        assert_eq!(count_to.call(0), 1);
+    }
+}
+
+#[test]
+fn test_gep_struct_field_round_trip() {
+    let context = Context::create();
+    let module = context.create_module("gep");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let struct_type = context.struct_type(&[i64_type.into(), i32_type.into()], false);
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("second_field", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let a = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let b = fn_value.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let struct_ptr = builder.build_alloca(struct_type, "pair");
+    let zero = i32_type.const_zero();
+    let one = i32_type.const_int(1, false);
+
+    // The leading zero index steps through the pointer itself, the second selects field 1
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let field_ptr = unsafe { builder.build_gep(struct_ptr, &[zero, one], "field_ptr") };
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let field_ptr = unsafe { builder.build_gep(struct_type, struct_ptr, &[zero, one], "field_ptr") };
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let struct_field_ptr = builder.build_struct_gep(struct_ptr, 1, "struct_field_ptr").unwrap();
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let struct_field_ptr = builder
+        .build_struct_gep(struct_type, struct_ptr, 1, "struct_field_ptr")
+        .unwrap();
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert!(builder.build_struct_gep(struct_ptr, 2, "out_of_bounds").is_err());
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    assert!(builder
+        .build_struct_gep(struct_type, struct_ptr, 2, "out_of_bounds")
+        .is_err());
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert_eq!(field_ptr.get_type(), i32_type.ptr_type(AddressSpace::default()));
+
+    builder.build_store(field_ptr, a);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let stored = builder.build_load(struct_field_ptr, "stored").into_int_value();
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let stored = builder
+        .build_load(i32_type, struct_field_ptr, "stored")
+        .into_int_value();
+
+    let sum = builder.build_int_add(stored, b, "sum");
+
+    builder.build_return(Some(&sum));
+
+    assert!(fn_value.verify(false));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let second_field = execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32) -> i32>("second_field")
+            .unwrap();
+
+        assert_eq!(second_field.call(40, 2), 42);
+        assert_eq!(second_field.call(-1, 1), 0);
+    }
+}
+
+#[test]
+fn test_int_casts_wraparound() {
+    let context = Context::create();
+    let module = context.create_module("casts");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+
+    // fn wrapping_add(a: u8, b: u8) -> u8, computed in i32 and truncated back
+    let fn_type = i8_type.fn_type(&[i8_type.into(), i8_type.into()], false);
+    let fn_value = module.add_function("wrapping_add", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let a = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let b = fn_value.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let wide_a = builder.build_int_z_extend(a, i32_type, "wide_a");
+    let wide_b = builder.build_int_z_extend(b, i32_type, "wide_b");
+
+    assert_eq!(wide_a.get_type(), i32_type);
+
+    let wide_sum = builder.build_int_add(wide_a, wide_b, "wide_sum");
+    let sum = builder.build_int_truncate(wide_sum, i8_type, "sum");
+
+    assert_eq!(sum.get_type(), i8_type);
+
+    builder.build_return(Some(&sum));
+
+    // fn widen(a: i8) -> i32, sign extending
+    let fn_type = i32_type.fn_type(&[i8_type.into()], false);
+    let fn_value = module.add_function("widen", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let a = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let widened = builder.build_int_s_extend(a, i32_type, "widened");
+
+    builder.build_return(Some(&widened));
+
+    // fn round_trip(a: i32) -> f64, through int -> float -> int -> float conversions
+    let fn_type = f64_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("round_trip", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let a = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let float = builder.build_signed_int_to_float(a, f64_type, "float");
+
+    assert_eq!(float.get_type(), f64_type);
+
+    let halved = builder.build_float_div(float, f64_type.const_float(2.0), "halved");
+    let truncated = builder.build_float_to_signed_int(halved, i32_type, "truncated");
+    let result = builder.build_signed_int_to_float(truncated, f64_type, "result");
+
+    builder.build_return(Some(&result));
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let wrapping_add = execution_engine
+            .get_function::<unsafe extern "C" fn(u8, u8) -> u8>("wrapping_add")
+            .unwrap();
+        let widen = execution_engine
+            .get_function::<unsafe extern "C" fn(i8) -> i32>("widen")
+            .unwrap();
+        let round_trip = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> f64>("round_trip")
+            .unwrap();
+
+        assert_eq!(wrapping_add.call(2, 3), 5);
+        assert_eq!(wrapping_add.call(200, 100), 44);
+        assert_eq!(wrapping_add.call(255, 1), 0);
+        assert_eq!(widen.call(-1), -1);
+        assert_eq!(widen.call(127), 127);
+        assert_eq!(round_trip.call(7), 3.0);
+        assert_eq!(round_trip.call(-7), -3.0);
+    }
+}
+
+#[test]
+fn test_i128_add_wraparound_jit() {
+    let context = Context::create();
+    let module = context.create_module("i128");
+    let builder = context.create_builder();
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    let i128_type = context.i128_type();
+
+    assert_eq!(i128_type.get_bit_width(), 128);
+
+    let fn_type = i128_type.fn_type(&[i128_type.into(), i128_type.into()], false);
+    let fn_value = module.add_function("add_i128", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let lhs = fn_value.get_first_param().unwrap().into_int_value();
+    let rhs = fn_value.get_last_param().unwrap().into_int_value();
+    let sum = builder.build_int_add(lhs, rhs, "sum");
+
+    builder.build_return(Some(&sum));
+
+    assert!(fn_value.verify(true));
+
+    unsafe {
+        let add_i128 = execution_engine
+            .get_function::<unsafe extern "C" fn(i128, i128) -> i128>("add_i128")
+            .unwrap();
+
+        assert_eq!(add_i128.call(1 << 100, 1 << 100), 1 << 101);
+        assert_eq!(add_i128.call(u64::MAX as i128, 1), 1 << 64);
+        assert_eq!(add_i128.call(i128::MAX, 1), i128::MIN);
+        assert_eq!(add_i128.call(-1, -1), -2);
+    }
+}
@@ -81,3 +81,41 @@ fn test_get_struct_type() {
 
     assert_eq!(context.get_struct_type("non-existent"), None);
 }
+
+#[test]
+fn test_context_drop_loop() {
+    for i in 0..16 {
+        let context = Context::create();
+        let module = context.create_module(&format!("module_{}", i));
+        let fn_type = context.void_type().fn_type(&[], false);
+
+        module.add_function("f", fn_type, None);
+
+        assert_eq!(module.get_context(), context);
+    }
+}
+
+#[test]
+fn test_context_ref_does_not_dispose() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+
+    for _ in 0..4 {
+        let context_ref = module.get_context();
+
+        assert_eq!(context_ref, context);
+        assert_eq!(context_ref.i32_type(), context.i32_type());
+    }
+
+    // The parent context must still be usable once every ContextRef has been dropped
+    let i32_type = context.i32_type();
+    let fn_value = module.add_function("f", i32_type.fn_type(&[], false), None);
+    let builder = context.create_builder();
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(Some(&i32_type.const_int(1, false)));
+
+    assert!(module.verify().is_ok());
+    assert_eq!(fn_value.get_type().get_context(), context);
+}
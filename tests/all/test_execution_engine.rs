@@ -1,5 +1,5 @@
 use inkwell::context::Context;
-use inkwell::execution_engine::FunctionLookupError;
+use inkwell::execution_engine::{ExecutionEngine, FunctionLookupError};
 use inkwell::targets::{InitializationConfig, Target};
 use inkwell::{AddressSpace, IntPredicate, OptimizationLevel};
 
@@ -48,6 +48,77 @@ fn test_get_function_address() {
     }
 }
 
+#[test]
+fn test_get_function_address_call() {
+    let context = Context::create();
+    let module = context.create_module("add_module");
+    let builder = context.create_builder();
+    let i64_type = context.i64_type();
+    let fn_type = i64_type.fn_type(&[i64_type.into(), i64_type.into()], false);
+    let fn_value = module.add_function("add", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+
+    let lhs = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let rhs = fn_value.get_nth_param(1).unwrap().into_int_value();
+    let sum = builder.build_int_add(lhs, rhs, "sum");
+
+    builder.build_return(Some(&sum));
+
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    assert_eq!(
+        execution_engine.get_function_address("sub"),
+        Err(FunctionLookupError::FunctionNotFound)
+    );
+
+    let address = execution_engine.get_function_address("add").unwrap();
+    let add: extern "C" fn(i64, i64) -> i64 = unsafe { std::mem::transmute(address) };
+
+    assert_eq!(add(40, 2), 42);
+    assert_eq!(add(-1, 1), 0);
+}
+
+#[test]
+fn test_jit_function_outlives_execution_engine_handle() {
+    let context = Context::create();
+    let module = context.create_module("jit_fn_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("sum3", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+
+    let a = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let b = fn_value.get_nth_param(1).unwrap().into_int_value();
+    let c = fn_value.get_nth_param(2).unwrap().into_int_value();
+    let sum = builder.build_int_add(a, b, "sum");
+    let sum = builder.build_int_add(sum, c, "sum");
+
+    builder.build_return(Some(&sum));
+
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    let sum3 = unsafe {
+        execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32, i32) -> i32>("sum3")
+            .unwrap()
+    };
+
+    // The JitFunction keeps the underlying engine alive on its own
+    drop(execution_engine);
+
+    unsafe {
+        assert_eq!(sum3.call(1, 2, 3), 6);
+    }
+}
+
 #[test]
 fn test_jit_execution_engine() {
     let context = Context::create();
@@ -109,6 +180,64 @@ fn test_jit_execution_engine() {
     assert_eq!(ret, 42, "unexpected main return code: {}", ret);
 }
 
+#[test]
+fn test_jit_execution_engine_optimization_levels() {
+    let levels = [
+        OptimizationLevel::None,
+        OptimizationLevel::Less,
+        OptimizationLevel::Default,
+        OptimizationLevel::Aggressive,
+    ];
+
+    for (level, expected) in levels.into_iter().zip(0..) {
+        assert_eq!(level as u32, expected);
+
+        let context = Context::create();
+        let module = context.create_module("sum_module");
+        let builder = context.create_builder();
+        let i64_type = context.i64_type();
+        let fn_type = i64_type.fn_type(&[i64_type.into()], false);
+        let fn_value = module.add_function("sum_to", fn_type, None);
+        let n = fn_value.get_first_param().unwrap().into_int_value();
+        let entry = context.append_basic_block(fn_value, "entry");
+        let loop_block = context.append_basic_block(fn_value, "loop");
+        let exit = context.append_basic_block(fn_value, "exit");
+        let zero = i64_type.const_zero();
+        let one = i64_type.const_int(1, false);
+
+        builder.position_at_end(entry);
+        builder.build_unconditional_branch(loop_block);
+        builder.position_at_end(loop_block);
+
+        // Sums 1..=n, which the optimizer may well turn into a closed form
+        let i = builder.build_phi(i64_type, "i");
+        let acc = builder.build_phi(i64_type, "acc");
+        let next_i = builder.build_int_add(i.as_basic_value().into_int_value(), one, "next_i");
+        let next_acc = builder.build_int_add(acc.as_basic_value().into_int_value(), next_i, "next_acc");
+        let done = builder.build_int_compare(IntPredicate::UGE, next_i, n, "done");
+
+        i.add_incoming(&[(&zero, entry), (&next_i, loop_block)]);
+        acc.add_incoming(&[(&zero, entry), (&next_acc, loop_block)]);
+        builder.build_conditional_branch(done, exit, loop_block);
+        builder.position_at_end(exit);
+        builder.build_return(Some(&next_acc));
+
+        assert!(module.verify().is_ok());
+
+        let execution_engine = module.create_jit_execution_engine(level).unwrap();
+
+        unsafe {
+            let sum_to = execution_engine
+                .get_function::<unsafe extern "C" fn(u64) -> u64>("sum_to")
+                .unwrap();
+
+            assert_eq!(sum_to.call(1), 1, "{:?}", level);
+            assert_eq!(sum_to.call(100), 5050, "{:?}", level);
+            assert_eq!(sum_to.call(100_000), 5_000_050_000, "{:?}", level);
+        }
+    }
+}
+
 // #[test]
 // fn test_execution_engine_empty_module() {
 //     let context = Context::create();
@@ -134,6 +263,77 @@ fn test_interpreter_execution_engine() {
     assert!(module.create_interpreter_execution_engine().is_ok());
 }
 
+#[test]
+fn test_execution_engine_modes_reject_owned_module() {
+    let context = Context::create();
+    let already_owned = "This module is already owned by an ExecutionEngine.";
+
+    let module = context.create_module("default_module");
+    let _execution_engine = module.create_execution_engine().unwrap();
+
+    assert_eq!(
+        module.create_execution_engine().unwrap_err().to_str(),
+        Ok(already_owned)
+    );
+
+    let module = context.create_module("interp_module");
+    let _execution_engine = module.create_interpreter_execution_engine().unwrap();
+
+    assert_eq!(
+        module
+            .create_jit_execution_engine(OptimizationLevel::None)
+            .unwrap_err()
+            .to_str(),
+        Ok(already_owned)
+    );
+
+    let module = context.create_module("jit_module");
+    let _execution_engine = module
+        .create_jit_execution_engine(OptimizationLevel::Aggressive)
+        .unwrap();
+
+    assert_eq!(
+        module.create_interpreter_execution_engine().unwrap_err().to_str(),
+        Ok(already_owned)
+    );
+}
+
+#[test]
+fn test_interpreter_run_function() {
+    let context = Context::create();
+    let module = context.create_module("interp_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("mul", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+
+    let lhs = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let rhs = fn_value.get_nth_param(1).unwrap().into_int_value();
+    let product = builder.build_int_mul(lhs, rhs, "product");
+
+    builder.build_return(Some(&product));
+
+    ExecutionEngine::link_in_interpreter();
+
+    let execution_engine = module.create_interpreter_execution_engine().unwrap();
+    let six = i32_type.create_generic_value(6, false);
+    let seven = i32_type.create_generic_value(7, false);
+
+    assert_eq!(i32_type.create_generic_value(0, false).int_width(), 32);
+
+    let result = unsafe { execution_engine.run_function(fn_value, &[&six, &seven]) };
+
+    assert_eq!(result.as_int(false), 42);
+
+    let minus_two = i32_type.create_generic_value(-2i64 as u64, true);
+    let result = unsafe { execution_engine.run_function(fn_value, &[&minus_two, &seven]) };
+
+    assert_eq!(result.as_int(true) as i32, -14);
+}
+
 #[test]
 fn test_add_remove_module() {
     let context = Context::create();
@@ -152,6 +352,91 @@ fn test_add_remove_module() {
     assert!(ee.remove_module(&module2).is_ok());
 }
 
+#[test]
+fn test_add_module_call_then_remove() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let module = context.create_module("main");
+    let ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    let module2 = context.create_module("incremental");
+    let fn_value = module2.add_function("seven", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+    builder.build_return(Some(&i32_type.const_int(7, false)));
+
+    assert!(ee.add_module(&module2).is_ok());
+
+    // Adding it a second time must not register it with the engine again
+    assert!(ee.add_module(&module2).is_err());
+
+    unsafe {
+        let seven = ee.get_function::<unsafe extern "C" fn() -> i32>("seven").unwrap();
+
+        assert_eq!(seven.call(), 7);
+    }
+
+    assert!(ee.remove_module(&module2).is_ok());
+    assert!(ee.get_function_value("seven").is_err());
+
+    // Removed modules are owned (and disposed) by the Module wrapper again
+    assert!(ee.remove_module(&module2).is_err());
+    assert_eq!(module2.get_function("seven"), Some(fn_value));
+}
+
+#[test]
+fn test_add_global_mapping() {
+    extern "C" fn host_add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    let context = Context::create();
+    let module = context.create_module("mapping");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let host_add_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let host_add_decl = module.add_function("host_add", host_add_type, None);
+    let host_result = module.add_global(i32_type, None, "host_result");
+    let fn_value = module.add_function("caller", i32_type.fn_type(&[], false), None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+
+    let args = [i32_type.const_int(40, false).into(), i32_type.const_int(2, false).into()];
+    let sum = builder
+        .build_call(host_add_decl, &args, "sum")
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+
+    builder.build_store(host_result.as_pointer_value(), sum);
+    builder.build_return(Some(&sum));
+
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    let mut result: i32 = 0;
+
+    execution_engine.add_global_mapping(&host_add_decl, host_add as usize);
+    execution_engine.add_global_mapping(&host_result, &mut result as *mut i32 as usize);
+
+    unsafe {
+        let caller = execution_engine
+            .get_function::<unsafe extern "C" fn() -> i32>("caller")
+            .unwrap();
+
+        assert_eq!(caller.call(), 42);
+    }
+
+    assert_eq!(result, 42);
+}
+
 // REVIEW: Global state pollution access tests cause this to pass when run individually
 // but fail when multiple tests are run
 // #[test]
@@ -219,3 +504,72 @@ fn test_add_remove_module() {
 //         module.create_jit_execution_engine(OptimizationLevel::None).unwrap()
 //     };
 // }
+
+#[test]
+fn test_global_string_ptr_passed_to_puts() {
+    use std::ffi::CStr;
+    use std::os::raw::c_char;
+
+    // Stands in for libc's puts, so we can check what it received
+    extern "C" fn host_puts(s: *const c_char) -> i32 {
+        let s = unsafe { CStr::from_ptr(s) };
+
+        if s.to_bytes() == b"hello" {
+            s.to_bytes().len() as i32
+        } else {
+            -1
+        }
+    }
+
+    let context = Context::create();
+    let module = context.create_module("hello");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::default());
+    let puts = module.add_function("puts", i32_type.fn_type(&[i8_ptr_type.into()], false), None);
+    let fn_value = module.add_function("say_hello", i32_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let hello = builder.build_global_string_ptr("hello\0", "hello");
+    let hello_again = builder.build_global_string_ptr("hello", "hello_again");
+    let ret = builder
+        .build_call(puts, &[hello.as_pointer_value().into()], "ret")
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+
+    builder.build_call(puts, &[hello_again.as_pointer_value().into()], "ret_again");
+    builder.build_return(Some(&ret));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    // Embedded NULs end the string, and a single terminator is always added
+    assert!(
+        ir.contains("@hello = private unnamed_addr constant [6 x i8] c\"hello\\00\""),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("@hello_again = private unnamed_addr constant [6 x i8] c\"hello\\00\""),
+        "{}",
+        ir
+    );
+
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    execution_engine.add_global_mapping(&puts, host_puts as usize);
+
+    unsafe {
+        let say_hello = execution_engine
+            .get_function::<unsafe extern "C" fn() -> i32>("say_hello")
+            .unwrap();
+
+        assert_eq!(say_hello.call(), 5);
+    }
+}
@@ -590,6 +590,197 @@ fn test_metadata_kinds() {
     ]);
 }
 
+#[test]
+fn test_inspect_and_erase_add() {
+    let context = Context::create();
+    let module = context.create_module("erase");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let function = module.add_function("f", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let lhs = function.get_nth_param(0).unwrap().into_int_value();
+    let rhs = function.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let sum = builder.build_int_add(lhs, rhs, "sum");
+    let unused = builder.build_int_mul(lhs, rhs, "unused");
+    let ret = builder.build_return(Some(&sum));
+
+    let add_instruction = sum.as_instruction_value().unwrap();
+    let mul_instruction = unused.as_instruction_value().unwrap();
+
+    assert_eq!(add_instruction.get_opcode(), Add);
+    assert_eq!(mul_instruction.get_opcode(), Mul);
+    assert_eq!(ret.get_opcode(), Return);
+    assert_eq!(add_instruction.get_parent(), Some(entry));
+    assert_eq!(add_instruction.get_previous_instruction(), None);
+    assert_eq!(add_instruction.get_next_instruction(), Some(mul_instruction));
+    assert_eq!(mul_instruction.get_next_instruction(), Some(ret));
+    assert_eq!(ret.get_next_instruction(), None);
+
+    mul_instruction.erase_from_basic_block();
+
+    assert_eq!(add_instruction.get_next_instruction(), Some(ret));
+    assert_eq!(ret.get_previous_instruction(), Some(add_instruction));
+    assert!(module.verify().is_ok());
+    assert!(!module.to_string().contains("unused"));
+}
+
+#[test]
+fn test_replace_add_zero_with_operand() {
+    let context = Context::create();
+    let module = context.create_module("rauw");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("f", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let x = function.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let a = builder.build_int_add(x, i32_type.const_zero(), "a");
+    let doubled = builder.build_int_add(a, a, "doubled");
+
+    builder.build_return(Some(&doubled));
+
+    let add_instruction = a.as_instruction_value().unwrap();
+
+    assert!(a.get_first_use().is_some());
+
+    // Replacing a value with itself is a no-op
+    a.replace_all_uses_with(a);
+
+    assert!(a.get_first_use().is_some());
+
+    a.replace_all_uses_with(x);
+
+    // a is now dead and can be erased without breaking the function
+    assert!(a.get_first_use().is_none());
+
+    add_instruction.erase_from_basic_block();
+
+    assert!(module.verify().is_ok());
+    assert!(module.to_string().contains("%doubled = add i32 %0, %0"));
+}
+
+#[test]
+#[should_panic(expected = "Replacement value must have the same type as the value being replaced")]
+fn test_replace_all_uses_with_type_mismatch() {
+    let context = Context::create();
+    let module = context.create_module("rauw");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i64_type.into()], false);
+    let function = module.add_function("f", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let x = function.get_nth_param(0).unwrap().into_int_value();
+    let y = function.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let a = builder.build_int_add(x, x, "a");
+
+    builder.build_return(Some(&a));
+
+    a.replace_all_uses_with(y);
+}
+
+#[test]
+fn test_walk_def_use_chain() {
+    let context = Context::create();
+    let module = context.create_module("def_use");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let function = module.add_function("f", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let a = function.get_nth_param(0).unwrap().into_int_value();
+    let b = function.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let c = builder.build_int_add(a, b, "c");
+    let d = builder.build_int_mul(c, a, "d");
+
+    builder.build_return(Some(&d));
+
+    let add_instruction = c.as_instruction_value().unwrap();
+
+    // a is used by both the add and the mul, in no particular order, and those users
+    // are given back as the values the instructions produce
+    let mut users = Vec::new();
+    let mut a_use = a.get_first_use();
+
+    while let Some(current) = a_use {
+        assert_eq!(current.get_used_value().left(), Some(a.into()));
+
+        users.push(current.get_user());
+        a_use = current.get_next_use();
+    }
+
+    assert_eq!(users.len(), 2);
+    assert!(users.contains(&c.into()));
+    assert!(users.contains(&d.into()));
+
+    // And from the other direction, the add's operands lead back to a and b
+    assert_eq!(add_instruction.get_num_operands(), 2);
+    assert_eq!(add_instruction.get_operand(0).unwrap().left(), Some(a.into()));
+    assert_eq!(add_instruction.get_operand(1).unwrap().left(), Some(b.into()));
+    assert!(add_instruction.get_operand(2).is_none());
+    assert_eq!(add_instruction.get_operand_use(0).unwrap().get_user(), add_instruction);
+}
+
+#[test]
+fn test_branch_weights_metadata_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("prof");
+    let builder = context.create_builder();
+    let bool_type = context.bool_type();
+    let i32_type = context.i32_type();
+    let fn_type = context.void_type().fn_type(&[bool_type.into()], false);
+    let function = module.add_function("branch", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let then_block = context.append_basic_block(function, "then");
+    let else_block = context.append_basic_block(function, "else");
+
+    builder.position_at_end(entry);
+
+    let cond = function.get_first_param().unwrap().into_int_value();
+    let branch = builder.build_conditional_branch(cond, then_block, else_block);
+
+    builder.position_at_end(then_block);
+    builder.build_return(None);
+    builder.position_at_end(else_block);
+    builder.build_return(None);
+
+    let prof_kind_id = context.get_kind_id("prof");
+    let md_string = context.metadata_string("branch_weights");
+    let weights = context.metadata_node(&[
+        md_string.into(),
+        i32_type.const_int(2000, false).into(),
+        i32_type.const_int(1, false).into(),
+    ]);
+
+    assert!(!branch.has_metadata());
+    assert!(branch.get_metadata(prof_kind_id).is_none());
+    assert!(branch.set_metadata(md_string, prof_kind_id).is_err());
+
+    branch.set_metadata(weights, prof_kind_id).unwrap();
+
+    assert!(branch.has_metadata());
+    assert_eq!(branch.get_metadata(prof_kind_id).unwrap().get_node_size(), 3);
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("br i1 %0, label %then, label %else, !prof !0"));
+    assert!(ir.contains("!0 = !{!\"branch_weights\", i32 2000, i32 1}"));
+}
+
 #[test]
 fn test_find_instruction_with_name() {
     use inkwell::context::Context;
@@ -618,3 +809,126 @@ fn test_find_instruction_with_name() {
     assert!(some_number.is_some());
     assert_eq!(some_number.unwrap().get_name().unwrap().to_str(), Ok("some_number"))
 }
+
+#[test]
+fn test_volatile_and_atomic_load_store_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("memory");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::default());
+    let fn_type = i32_type.fn_type(&[i32_ptr_type.into(), i32_ptr_type.into()], false);
+    let fn_value = module.add_function("mmio", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let device = fn_value.get_nth_param(0).unwrap().into_pointer_value();
+    let flag = fn_value.get_nth_param(1).unwrap().into_pointer_value();
+
+    builder.position_at_end(entry);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let status = builder.build_load(device, "status");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let status = builder.build_load(i32_type, device, "status");
+
+    status.as_instruction_value().unwrap().set_volatile(true).unwrap();
+
+    let store_instruction = builder.build_store(flag, status);
+
+    store_instruction.set_atomic_ordering(AtomicOrdering::Release).unwrap();
+    store_instruction.set_alignment(4).unwrap();
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let loaded = builder.build_load(flag, "loaded");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let loaded = builder.build_load(i32_type, flag, "loaded");
+    let load_instruction = loaded.as_instruction_value().unwrap();
+
+    load_instruction.set_atomic_ordering(AtomicOrdering::Acquire).unwrap();
+    load_instruction.set_alignment(4).unwrap();
+
+    builder.build_return(Some(&loaded));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("%status = load volatile i32"), "{}", ir);
+    assert!(ir.contains("store atomic i32 %status"), "{}", ir);
+    assert!(ir.contains("%loaded = load atomic i32"), "{}", ir);
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert!(
+        ir.contains("store atomic i32 %status, i32* %1 release, align 4"),
+        "{}",
+        ir
+    );
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert!(
+        ir.contains("%loaded = load atomic i32, i32* %1 acquire, align 4"),
+        "{}",
+        ir
+    );
+}
+
+#[test]
+fn test_alignment_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("alignment");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_value = module.add_function("aligned", i32_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let alloca = builder.build_alloca(i32_type, "slot");
+    let alloca_instruction = alloca.as_instruction().unwrap();
+
+    assert!(alloca_instruction.set_alignment(3).is_err());
+    assert!(alloca_instruction.set_alignment(16).is_ok());
+    assert_eq!(alloca_instruction.get_alignment(), Ok(16));
+
+    let store_instruction = builder.build_store(alloca, i32_type.const_int(1, false));
+
+    assert!(store_instruction.set_alignment(16).is_ok());
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let load = builder.build_load(alloca, "load");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let load = builder.build_load(i32_type, alloca, "load");
+    let load_instruction = load.as_instruction_value().unwrap();
+
+    assert!(load_instruction.set_alignment(8).is_ok());
+    assert_eq!(load_instruction.get_alignment(), Ok(8));
+
+    builder.build_return(Some(&load));
+
+    let global = module.add_global(i32_type, None, "aligned_global");
+
+    global.set_initializer(&i32_type.const_zero());
+
+    assert_eq!(global.get_alignment(), 0);
+
+    global.set_alignment(32);
+
+    assert_eq!(global.get_alignment(), 32);
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("%slot = alloca i32, align 16"), "{}", ir);
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert!(ir.contains("store i32 1, i32* %slot, align 16"), "{}", ir);
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert!(ir.contains("%load = load i32, i32* %slot, align 8"), "{}", ir);
+    assert!(ir.contains("@aligned_global = global i32 0, align 32"), "{}", ir);
+}
+
+#[test]
+#[should_panic(expected = "Alignment is not a power of 2!")]
+fn test_global_alignment_not_power_of_two() {
+    let context = Context::create();
+    let module = context.create_module("alignment");
+    let global = module.add_global(context.i32_type(), None, "misaligned");
+
+    global.set_alignment(12);
+}
@@ -1,8 +1,9 @@
 use inkwell::context::Context;
+use inkwell::data_layout::DataLayout;
 use inkwell::memory_buffer::MemoryBuffer;
-use inkwell::module::Module;
-use inkwell::targets::{Target, TargetTriple};
-use inkwell::values::AnyValue;
+use inkwell::module::{Linkage, Module};
+use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetData, TargetMachine, TargetTriple};
+use inkwell::values::{AnyValue, AsValueRef};
 use inkwell::OptimizationLevel;
 
 use std::env::temp_dir;
@@ -34,38 +35,34 @@ fn test_write_bitcode_to_path() {
     remove_file(&path).unwrap();
 }
 
-// REVIEW: This test infrequently fails. Seems to happen more often on travis.
-// Possibly a LLVM bug? Wrapper is really straightforward. See issue #6 on GH
-// #[test]
-// fn test_write_bitcode_to_file() {
-//     use context::Context;
-//     use std::env::temp_dir;
-//     use std::fs::{File, remove_file};
-//     use std::io::{Read, Seek, SeekFrom};
+#[test]
+fn test_write_bitcode_to_file() {
+    let mut path = temp_dir();
 
-//     let mut path = temp_dir();
+    path.push("temp2.bc");
 
-//     path.push("temp2.bc");
+    let file = File::create(&path).unwrap();
 
-//     let mut file = File::create(&path).unwrap();
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
 
-//     let context = Context::create();
-//     let module = context.create_module("my_module");
-//     let void_type = context.void_type();
-//     let fn_type = void_type.fn_type(&[], false);
+    module.add_function("my_fn", fn_type, None);
 
-//     module.add_function("my_fn", fn_type, None);
-//     module.write_bitcode_to_file(&file, true, false);
+    assert!(module.write_bitcode_to_file(&file, false, false));
 
-//     let mut contents = Vec::new();
-//     let mut file2 = File::open(&path).expect("Could not open temp file");
+    drop(file);
 
-//     file.read_to_end(&mut contents).expect("Unable to verify written file");
+    let mut contents = Vec::new();
+    let mut file = File::open(&path).expect("Could not open temp file");
 
-//     assert!(contents.len() > 0);
+    file.read_to_end(&mut contents).expect("Unable to verify written file");
 
-//     remove_file(&path).unwrap();
-// }
+    assert_eq!(contents, module.write_bitcode_to_memory().as_slice());
+
+    remove_file(&path).unwrap();
+}
 
 #[test]
 fn test_get_function() {
@@ -109,6 +106,78 @@ fn test_get_function() {
     assert_eq!(functions, vec![function_1, function_2]);
 }
 
+#[test]
+fn test_get_or_insert_function() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let fn_type = context.void_type().fn_type(&[], false);
+
+    let function_1 = module
+        .get_or_insert_function("my_fn", fn_type, Some(Linkage::Internal))
+        .unwrap();
+    let function_2 = module.get_or_insert_function("my_fn", fn_type, None).unwrap();
+
+    assert_eq!(function_1.as_value_ref(), function_2.as_value_ref());
+    assert_eq!(function_1.get_linkage(), Linkage::Internal);
+    assert_eq!(module.get_functions().count(), 1);
+
+    let other_fn_type = context.i32_type().fn_type(&[], false);
+
+    assert!(module.get_or_insert_function("my_fn", other_fn_type, None).is_err());
+    assert_eq!(module.get_functions().count(), 1);
+}
+
+#[test]
+fn test_get_functions_in_insertion_order() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let fn_type = context.void_type().fn_type(&[], false);
+
+    let function_1 = module.add_function("function_1", fn_type, None);
+    let function_2 = module.add_function("function_2", fn_type, None);
+    let function_3 = module.add_function("function_3", fn_type, None);
+
+    let mut functions = module.get_functions();
+
+    assert_eq!(functions.next(), Some(function_1));
+    assert_eq!(functions.next(), Some(function_2));
+    assert_eq!(functions.next(), Some(function_3));
+    assert!(functions.next().is_none());
+
+    let names: Vec<_> = module
+        .get_functions()
+        .map(|f| f.get_name().to_str().unwrap().to_owned())
+        .collect();
+
+    assert_eq!(names, ["function_1", "function_2", "function_3"]);
+}
+
+#[test]
+fn test_get_globals() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+
+    assert!(module.get_globals().next().is_none());
+
+    let global_1 = module.add_global(i8_type, None, "global_1");
+    let global_2 = module.add_global(i32_type, None, "global_2");
+
+    assert_eq!(module.get_first_global(), Some(global_1));
+    assert_eq!(module.get_last_global(), Some(global_2));
+    assert_eq!(module.get_global("global_2"), Some(global_2));
+
+    let globals: Vec<_> = module.get_globals().collect();
+
+    assert_eq!(globals, vec![global_1, global_2]);
+
+    // Functions are not global variables
+    module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+
+    assert_eq!(module.get_globals().count(), 2);
+}
+
 #[test]
 fn test_module_owned_data_layout_disposed_safely() {
     let context = Context::create();
@@ -116,6 +185,68 @@ fn test_module_owned_data_layout_disposed_safely() {
     context.create_module("test");
 }
 
+#[test]
+fn test_data_layout_from_string() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let layout_str = "e-m:e-p:32:32-i64:64-n32-S128";
+
+    assert_eq!(module.get_data_layout().as_str().to_str(), Ok(""));
+
+    {
+        let data_layout = DataLayout::create(layout_str);
+
+        assert_eq!(data_layout.as_str().to_str(), Ok(layout_str));
+        assert_eq!(data_layout, DataLayout::create(layout_str));
+        assert_ne!(data_layout, DataLayout::create(""));
+
+        module.set_data_layout(&data_layout);
+    }
+
+    // The module keeps its own copy once the `DataLayout` it was set from is dropped
+    assert_eq!(module.get_data_layout().as_str().to_str(), Ok(layout_str));
+    assert_eq!(*module.get_data_layout(), DataLayout::create(layout_str));
+    assert!(module
+        .to_string()
+        .contains(&format!("target datalayout = \"{}\"", layout_str)));
+
+    let target_data = TargetData::create(layout_str);
+
+    assert_eq!(target_data.get_data_layout(), *module.get_data_layout());
+    assert_eq!(target_data.get_pointer_byte_size(None), 4);
+}
+
+#[test]
+fn test_module_drop_loop_no_double_free() {
+    let context = Context::create();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    for i in 0..16 {
+        let module = context.create_module(&format!("module_{}", i));
+        let builder = context.create_builder();
+        let fn_val = module.add_function("f", fn_type, None);
+        let basic_block = context.append_basic_block(fn_val, "entry");
+
+        builder.position_at_end(basic_block);
+        builder.build_return(None);
+        drop(builder);
+
+        // Every other module hands ownership over to an ExecutionEngine, which
+        // must then be the only one to dispose of it.
+        if i % 2 == 0 {
+            let execution_engine = module
+                .create_execution_engine()
+                .expect("Could not create Execution Engine");
+
+            drop(module);
+            drop(execution_engine);
+        } else {
+            drop(module);
+        }
+    }
+}
+
 #[test]
 fn test_write_and_load_memory_buffer() {
     let context = Context::create();
@@ -132,6 +263,8 @@ fn test_write_and_load_memory_buffer() {
     let memory_buffer = module.write_bitcode_to_memory();
 
     assert!(memory_buffer.get_size() > 0);
+    assert_eq!(memory_buffer.as_slice().len(), memory_buffer.get_size());
+    assert_eq!(&memory_buffer.as_slice()[..4], b"BC\xC0\xDE");
 
     let module2 = context.create_module_from_ir(memory_buffer).unwrap();
 
@@ -146,6 +279,35 @@ fn test_write_and_load_memory_buffer() {
     assert!(object_file.is_err());
 }
 
+#[test]
+fn test_memory_buffer_from_file() {
+    let mut path = temp_dir();
+
+    path.push("temp3.bc");
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    module.add_function("my_fn", fn_type, None);
+
+    assert!(module.write_bitcode_to_path(&path));
+
+    let memory_buffer = MemoryBuffer::create_from_file(&path).unwrap();
+
+    assert!(memory_buffer.get_size() > 0);
+    assert_eq!(memory_buffer.as_slice(), module.write_bitcode_to_memory().as_slice());
+
+    let module2 = Module::parse_bitcode_from_buffer(&memory_buffer, &context).unwrap();
+
+    assert!(module2.get_function("my_fn").is_some());
+
+    remove_file(&path).unwrap();
+
+    assert!(MemoryBuffer::create_from_file(&path).is_err());
+}
+
 #[test]
 fn test_garbage_ir_fails_create_module_from_ir() {
     let context = Context::create();
@@ -166,6 +328,34 @@ fn test_garbage_ir_fails_create_module_from_ir_copy() {
     assert!(context.create_module_from_ir(memory_buffer).is_err());
 }
 
+#[test]
+fn test_parse_textual_ir() {
+    let context = Context::create();
+    let ir = b"define i32 @add(i32 %a, i32 %b) {\nentry:\n  %sum = add i32 %a, %b\n  ret i32 %sum\n}\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(ir, "add_ir");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+
+    assert!(module.get_function("add").is_some());
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module
+        .create_jit_execution_engine(OptimizationLevel::None)
+        .expect("Could not create Execution Engine");
+
+    unsafe {
+        let add = execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32) -> i32>("add")
+            .unwrap();
+
+        assert_eq!(add.call(40, 2), 42);
+    }
+
+    let bad_ir = b"define i32 @add( {";
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(bad_ir, "bad_ir");
+
+    assert!(!context.create_module_from_ir(memory_buffer).unwrap_err().is_empty());
+}
+
 #[test]
 fn test_get_struct_type() {
     let context = Context::create();
@@ -242,7 +432,14 @@ fn test_parse_from_buffer() {
     let module2_result = Module::parse_bitcode_from_buffer(&buffer, &context);
 
     assert!(module2_result.is_ok());
-    assert_eq!(module2_result.unwrap().get_context(), context);
+
+    let module2 = module2_result.unwrap();
+
+    assert_eq!(module2.get_context(), context);
+    assert_eq!(
+        module2.get_function("f").unwrap().print_to_string(),
+        f.print_to_string()
+    );
 
     let module3_result = Module::parse_bitcode_from_buffer(&garbage_buffer, &context);
 
@@ -309,6 +506,66 @@ fn test_clone() {
 
     assert_ne!(module, module2);
     assert_eq!(module.print_to_string(), module2.print_to_string());
+
+    module2.set_name("mod_clone");
+
+    assert_eq!(module.get_name().to_str(), Ok("mod"));
+    assert_eq!(module2.get_name().to_str(), Ok("mod_clone"));
+}
+
+#[test]
+fn test_clone_is_independent() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let f = module.add_function("f", fn_type, None);
+    let basic_block = context.append_basic_block(f, "entry");
+    let builder = context.create_builder();
+
+    builder.position_at_end(basic_block);
+    builder.build_return(None);
+
+    // Cloning a module owned by an execution engine yields one which is not
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    let module2 = module.clone();
+
+    assert_eq!(module2.get_context(), context);
+    assert_eq!(module2.get_function("f").unwrap().get_type().get_context(), context);
+
+    module2.add_function("g", fn_type, None);
+
+    assert!(module.get_function("g").is_none());
+    assert!(module2.get_function("g").is_some());
+    assert_eq!(module.get_functions().count(), 1);
+    assert_eq!(module2.get_functions().count(), 2);
+
+    // The clone can be handed to another execution engine
+    let execution_engine2 = module2.create_execution_engine().unwrap();
+
+    drop(execution_engine2);
+    drop(module2);
+
+    assert!(execution_engine.get_function_value("f").is_ok());
+}
+
+#[test]
+fn test_print_to_string() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let f = module.add_function("my_fn", fn_type, None);
+    let basic_block = context.append_basic_block(f, "entry");
+    let builder = context.create_builder();
+
+    builder.position_at_end(basic_block);
+    builder.build_return(None);
+
+    let ir = module.print_to_string();
+
+    assert!(ir.to_str().unwrap().contains("define void @my_fn()"));
+    assert_eq!(ir.to_string(), module.to_string());
 }
 
 #[test]
@@ -333,9 +590,19 @@ fn test_print_to_file() {
 
     let mut temp_path = temp_dir();
 
-    temp_path.push("module");
+    temp_path.push("module.ll");
 
     assert!(module.print_to_file(&temp_path).is_ok());
+
+    let mut contents = String::new();
+    let mut file = File::open(&temp_path).expect("Could not open temp file");
+
+    file.read_to_string(&mut contents).expect("Unable to verify written file");
+
+    assert!(contents.starts_with("; ModuleID"));
+    assert_eq!(contents, module.to_string());
+
+    remove_file(&temp_path).unwrap();
 }
 
 #[test]
@@ -438,6 +705,55 @@ fn test_linking_modules() {
     assert_eq!(execution_engine2.get_function_value("f4"), Ok(fn_val4));
 }
 
+#[test]
+fn test_linking_resolves_extern_declaration() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+
+    // Runtime library defining `forty_two`
+    let runtime = context.create_module("runtime");
+    let forty_two = runtime.add_function("forty_two", fn_type, None);
+    let entry = context.append_basic_block(forty_two, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(Some(&i32_type.const_int(42, false)));
+
+    // Generated code only declaring `forty_two`
+    let module = context.create_module("main");
+    let decl = module.add_function("forty_two", fn_type, None);
+    let caller = module.add_function("caller", fn_type, None);
+    let entry = context.append_basic_block(caller, "entry");
+
+    builder.position_at_end(entry);
+
+    let ret = builder
+        .build_call(decl, &[], "call")
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+
+    builder.build_return(Some(&ret));
+
+    assert_eq!(module.get_function("forty_two").unwrap().count_basic_blocks(), 0);
+    assert!(module.link_in_module(runtime).is_ok());
+    assert_eq!(module.get_function("forty_two").unwrap().count_basic_blocks(), 1);
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module
+        .create_jit_execution_engine(OptimizationLevel::None)
+        .expect("Could not create Execution Engine");
+
+    unsafe {
+        let caller = execution_engine
+            .get_function::<unsafe extern "C" fn() -> i32>("caller")
+            .unwrap();
+
+        assert_eq!(caller.call(), 42);
+    }
+}
+
 #[test]
 fn test_metadata_flags() {
     #[cfg(not(any(feature = "llvm4-0", feature = "llvm5-0", feature = "llvm6-0")))]
@@ -476,6 +792,68 @@ fn test_metadata_flags() {
     }
 }
 
+#[llvm_versions(7.0..=latest)]
+#[test]
+fn test_module_flags_in_ir() {
+    use inkwell::debug_info::debug_metadata_version;
+    use inkwell::module::FlagBehavior;
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let version = i32_type.const_int(debug_metadata_version() as u64, false);
+
+    module.add_basic_value_flag("Debug Info Version", FlagBehavior::Warning, version);
+
+    let md_string = context.metadata_string("my value");
+
+    module.add_metadata_flag("my flag", FlagBehavior::Override, md_string);
+
+    assert!(module.get_flag("Debug Info Version").is_some());
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("!llvm.module.flags = !{!0, !1}"));
+    assert!(ir.contains(&format!(
+        "!0 = !{{i32 2, !\"Debug Info Version\", i32 {}}}",
+        debug_metadata_version()
+    )));
+    assert!(ir.contains("!1 = !{i32 4, !\"my flag\", !\"my value\"}"));
+}
+
+#[test]
+fn test_named_metadata_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let ident = context.metadata_node(&[context.metadata_string("my compiler 1.0").into()]);
+
+    assert_eq!(module.get_global_metadata_size("llvm.ident"), 0);
+
+    module.add_global_metadata("llvm.ident", &ident).unwrap();
+
+    assert_eq!(module.get_global_metadata_size("llvm.ident"), 1);
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("!llvm.ident = !{!0}"));
+    assert!(ir.contains("!0 = !{!\"my compiler 1.0\"}"));
+
+    let operands = module.get_global_metadata("llvm.ident");
+    let node_values = operands[0].get_node_values();
+
+    assert_eq!(node_values.len(), 1);
+    assert_eq!(
+        node_values[0]
+            .into_metadata_value()
+            .get_string_value()
+            .unwrap()
+            .to_str(),
+        Ok("my compiler 1.0")
+    );
+}
+
 #[test]
 fn test_double_ee_from_same_module() {
     let context = Context::create();
@@ -511,3 +889,79 @@ fn test_double_ee_from_same_module() {
 
     assert!(module.create_interpreter_execution_engine().is_err());
 }
+
+#[test]
+fn test_verify_returns_message() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", fn_type, None);
+
+    assert!(module.verify().is_ok());
+
+    // A basic block without a terminator is invalid
+    context.append_basic_block(function, "entry");
+
+    let err = module.verify().unwrap_err();
+
+    assert!(!err.to_string().is_empty());
+}
+
+#[test]
+fn test_inline_assembly() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    module.set_inline_assembly(".globl foo");
+
+    assert!(module.to_string().contains("module asm \".globl foo\""));
+
+    #[cfg(not(any(feature = "llvm4-0", feature = "llvm5-0", feature = "llvm6-0")))]
+    {
+        module.append_inline_assembly(".globl bar");
+
+        let ir = module.to_string();
+
+        assert!(ir.contains("module asm \".globl foo\""));
+        assert!(ir.contains("module asm \".globl bar\""));
+    }
+}
+
+#[test]
+fn test_set_triple_from_machine() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&triple).unwrap();
+    let target_machine = target
+        .create_target_machine(
+            &triple,
+            "",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    assert_eq!(module.get_triple(), TargetTriple::create(""));
+
+    module.set_triple_from_machine(&target_machine);
+
+    let data_layout = target_machine.get_target_data().get_data_layout();
+
+    assert_eq!(module.get_triple(), target_machine.get_triple());
+    assert_eq!(*module.get_data_layout(), data_layout);
+
+    let ir = module.to_string();
+
+    assert!(ir.contains(&format!("target triple = \"{}\"", triple.as_str().to_str().unwrap())));
+    assert!(ir.contains(&format!(
+        "target datalayout = \"{}\"",
+        data_layout.as_str().to_str().unwrap()
+    )));
+}
@@ -1,11 +1,13 @@
 use inkwell::context::Context;
+use inkwell::module::Linkage;
 use inkwell::passes::{PassManager, PassManagerBuilder, PassRegistry};
+use inkwell::values::InstructionOpcode;
 
 #[llvm_versions(13.0..=latest)]
 use inkwell::passes::PassBuilderOptions;
 #[llvm_versions(13.0..=latest)]
 use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetMachine};
-use inkwell::OptimizationLevel;
+use inkwell::{AddressSpace, IntPredicate, OptimizationLevel};
 
 #[test]
 fn test_init_all_passes_for_module() {
@@ -221,6 +223,247 @@ fn test_pass_manager_builder() {
     assert!(module_pass_manager.run_on(&module));
 }
 
+#[test]
+fn test_pass_manager_builder_removes_dead_store() {
+    let pass_manager_builder = PassManagerBuilder::create();
+
+    pass_manager_builder.set_optimization_level(OptimizationLevel::Default);
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let fn_pass_manager = PassManager::create(&module);
+
+    pass_manager_builder.populate_function_pass_manager(&fn_pass_manager);
+
+    let i32_type = context.i32_type();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let builder = context.create_builder();
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    // The stored value is never read back
+    let ptr = builder.build_alloca(i32_type, "ptr");
+
+    builder.build_store(ptr, i32_type.const_int(42, false));
+    builder.build_return(None);
+
+    assert_eq!(
+        entry.get_first_instruction().unwrap().get_opcode(),
+        InstructionOpcode::Alloca
+    );
+
+    fn_pass_manager.initialize();
+
+    assert!(fn_pass_manager.run_on(&fn_value));
+
+    fn_pass_manager.finalize();
+
+    assert_eq!(
+        entry.get_first_instruction().unwrap().get_opcode(),
+        InstructionOpcode::Return
+    );
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_promote_memory_to_register_removes_allocas() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("max", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let then_block = context.append_basic_block(fn_value, "then");
+    let else_block = context.append_basic_block(fn_value, "else");
+    let merge_block = context.append_basic_block(fn_value, "merge");
+    let a = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let b = fn_value.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let a_ptr = builder.build_alloca(i32_type, "a_ptr");
+    let b_ptr = builder.build_alloca(i32_type, "b_ptr");
+    let result_ptr = builder.build_alloca(i32_type, "result_ptr");
+
+    builder.build_store(a_ptr, a);
+    builder.build_store(b_ptr, b);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let (a, b) = (builder.build_load(a_ptr, "a"), builder.build_load(b_ptr, "b"));
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let (a, b) = (
+        builder.build_load(i32_type, a_ptr, "a"),
+        builder.build_load(i32_type, b_ptr, "b"),
+    );
+    let (a, b) = (a.into_int_value(), b.into_int_value());
+    let cond = builder.build_int_compare(IntPredicate::SGT, a, b, "cond");
+
+    builder.build_conditional_branch(cond, then_block, else_block);
+    builder.position_at_end(then_block);
+    builder.build_store(result_ptr, a);
+    builder.build_unconditional_branch(merge_block);
+    builder.position_at_end(else_block);
+    builder.build_store(result_ptr, b);
+    builder.build_unconditional_branch(merge_block);
+    builder.position_at_end(merge_block);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let result = builder.build_load(result_ptr, "result");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let result = builder.build_load(i32_type, result_ptr, "result");
+
+    builder.build_return(Some(&result));
+
+    let count_allocas = || {
+        let mut count = 0;
+
+        for block in fn_value.get_basic_blocks() {
+            let mut instruction = block.get_first_instruction();
+
+            while let Some(current) = instruction {
+                if current.get_opcode() == InstructionOpcode::Alloca {
+                    count += 1;
+                }
+
+                instruction = current.get_next_instruction();
+            }
+        }
+
+        count
+    };
+
+    assert_eq!(count_allocas(), 3);
+
+    // Alias analysis is scheduled first, as it is in a typical -O1 pipeline
+    let fn_pass_manager = PassManager::create(&module);
+
+    fn_pass_manager.add_basic_alias_analysis_pass();
+    fn_pass_manager.add_promote_memory_to_register_pass();
+    fn_pass_manager.add_instruction_combining_pass();
+    fn_pass_manager.add_reassociate_pass();
+    fn_pass_manager.add_gvn_pass();
+    fn_pass_manager.add_cfg_simplification_pass();
+    fn_pass_manager.initialize();
+
+    assert!(fn_pass_manager.run_on(&fn_value));
+
+    fn_pass_manager.finalize();
+
+    assert_eq!(count_allocas(), 0);
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let max = execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32) -> i32>("max")
+            .unwrap();
+
+        assert_eq!(max.call(3, 7), 7);
+        assert_eq!(max.call(-1, -9), -1);
+    }
+}
+
+#[test]
+fn test_function_pass_manager_simplify_cfg_changed() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let next = context.append_basic_block(fn_value, "next");
+
+    // The unconditional branch into a block with a single predecessor can be folded away
+    builder.position_at_end(entry);
+    builder.build_unconditional_branch(next);
+    builder.position_at_end(next);
+    builder.build_return(None);
+
+    let fn_pass_manager = PassManager::create(&module);
+
+    fn_pass_manager.add_cfg_simplification_pass();
+
+    assert!(!fn_pass_manager.initialize());
+    assert!(fn_pass_manager.run_on(&fn_value));
+    assert_eq!(fn_value.count_basic_blocks(), 1);
+
+    // Nothing changes the second time around
+    assert!(!fn_pass_manager.run_on(&fn_value));
+    assert!(!fn_pass_manager.finalize());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_module_pass_manager_global_dce() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let unused = module.add_global(i32_type, None, "unused");
+    let used = module.add_global(i32_type, None, "used");
+
+    unused.set_linkage(Linkage::Internal);
+    unused.set_initializer(&i32_type.const_zero());
+    used.set_initializer(&i32_type.const_zero());
+
+    let pass_manager = PassManager::create(());
+
+    pass_manager.add_global_dce_pass();
+
+    assert!(pass_manager.run_on(&module));
+    assert!(module.get_global("unused").is_none());
+    assert!(module.get_global("used").is_some());
+
+    // Nothing left to remove
+    assert!(!pass_manager.run_on(&module));
+}
+
+#[llvm_versions(7.0..=latest)]
+#[test]
+fn test_constant_merge_unnamed_addr_strings() {
+    use inkwell::values::UnnamedAddress;
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let string = context.const_string(b"hello", true);
+    let ptr_type = string.get_type().ptr_type(AddressSpace::default());
+    let sink = module.add_function("sink", context.void_type().fn_type(&[ptr_type.into()], false), None);
+    let fn_value = module.add_function("use_strings", context.void_type().fn_type(&[], false), None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    for name in ["first", "second"] {
+        let global = module.add_global(string.get_type(), None, name);
+
+        global.set_initializer(&string);
+        global.set_constant(true);
+        global.set_linkage(Linkage::Private);
+        global.set_unnamed_address(UnnamedAddress::Global);
+
+        assert!(global.has_unnamed_addr());
+        assert!(global.to_string().contains("private unnamed_addr constant"));
+
+        builder.build_call(sink, &[global.as_pointer_value().into()], "");
+    }
+
+    builder.build_return(None);
+
+    let pass_manager = PassManager::create(());
+
+    pass_manager.add_constant_merge_pass();
+
+    assert!(pass_manager.run_on(&module));
+
+    // Both calls now pass the one remaining copy of the string
+    assert_eq!(module.get_globals().count(), 1);
+    assert!(module.verify().is_ok());
+}
+
 #[test]
 fn test_pass_registry() {
     let pass_registry = PassRegistry::get_global();
@@ -1,4 +1,5 @@
 use inkwell::context::Context;
+use inkwell::module::Linkage;
 use inkwell::targets::{
     ByteOrdering, CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetData, TargetMachine, TargetTriple,
 };
@@ -145,11 +146,16 @@ fn test_target_and_target_machine() {
 
     #[cfg(not(any(feature = "llvm4-0", feature = "llvm5-0", feature = "llvm6-0")))]
     {
-        // TODO: Try and find a triple that actually gets normalized..
         assert_eq!(
             TargetMachine::normalize_triple(&triple).as_str().to_str(),
             Ok("x86_64-pc-linux-gnu"),
         );
+        assert_eq!(
+            TargetMachine::normalize_triple(&TargetTriple::create("x86_64-linux-gnu"))
+                .as_str()
+                .to_str(),
+            Ok("x86_64-unknown-linux-gnu"),
+        );
 
         let _host_name = TargetMachine::get_host_cpu_name();
         let _host_cpu_features = TargetMachine::get_host_cpu_features();
@@ -175,6 +181,121 @@ fn test_default_triple() {
     // TODO: CFG for other supported major OSes
 }
 
+#[test]
+fn test_default_triple_on_module() {
+    let context = Context::create();
+    let module = context.create_module("default_triple");
+    let default_triple = TargetMachine::get_default_triple();
+
+    assert!(!default_triple.as_str().to_bytes().is_empty());
+
+    #[cfg(not(any(feature = "llvm4-0", feature = "llvm5-0", feature = "llvm6-0")))]
+    let default_triple = TargetMachine::normalize_triple(&default_triple);
+
+    module.set_triple(&default_triple);
+
+    assert_eq!(module.get_triple(), default_triple);
+}
+
+#[test]
+fn test_host_target_data_struct_offsets() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&triple).unwrap();
+    let target_machine = target
+        .create_target_machine(
+            &triple,
+            "",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+    let target_data = target_machine.get_target_data();
+
+    let context = Context::create();
+    let i8_type = context.i8_type();
+    let i16_type = context.i16_type();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let ptr_type = i8_type.ptr_type(AddressSpace::default());
+
+    // Equivalent to `#[repr(C)] struct { a: u8, b: u32, c: u16, d: u64 }`
+    let struct_type = context.struct_type(
+        &[i8_type.into(), i32_type.into(), i16_type.into(), i64_type.into()],
+        false,
+    );
+    let packed_type = context.struct_type(&[i8_type.into(), i32_type.into()], true);
+    let empty_type = context.struct_type(&[], false);
+
+    assert_eq!(
+        target_data.get_pointer_byte_size(None) as usize,
+        std::mem::size_of::<*const u8>()
+    );
+    assert_eq!(
+        target_data.get_store_size(&ptr_type) as usize,
+        std::mem::size_of::<*const u8>()
+    );
+    assert_eq!(
+        target_data.get_abi_alignment(&i64_type) as usize,
+        std::mem::align_of::<u64>()
+    );
+
+    #[repr(C)]
+    struct Mirror {
+        a: u8,
+        b: u32,
+        c: u16,
+        d: u64,
+    }
+
+    let mirror = Mirror { a: 0, b: 0, c: 0, d: 0 };
+    let base = &mirror as *const Mirror as usize;
+    let offsets = [
+        &mirror.a as *const u8 as usize - base,
+        &mirror.b as *const u32 as usize - base,
+        &mirror.c as *const u16 as usize - base,
+        &mirror.d as *const u64 as usize - base,
+    ];
+
+    for (i, offset) in offsets.iter().enumerate() {
+        assert_eq!(
+            target_data.offset_of_element(&struct_type, i as u32),
+            Some(*offset as u64)
+        );
+    }
+
+    assert_eq!(target_data.offset_of_element(&struct_type, 4), None);
+    assert_eq!(
+        target_data.get_abi_size(&struct_type) as usize,
+        std::mem::size_of::<Mirror>()
+    );
+    assert_eq!(
+        target_data.get_abi_alignment(&struct_type) as usize,
+        std::mem::align_of::<Mirror>()
+    );
+
+    assert_eq!(target_data.offset_of_element(&packed_type, 1), Some(1));
+    assert_eq!(target_data.get_abi_size(&packed_type), 5);
+
+    assert_eq!(target_data.offset_of_element(&empty_type, 0), None);
+}
+
+#[test]
+fn test_initialize_native_host_lookup() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&triple).unwrap();
+
+    assert!(!target.get_name().to_bytes().is_empty());
+    assert!(target.has_target_machine());
+    assert!(target.has_asm_backend());
+    assert_eq!(Target::from_name(target.get_name().to_str().unwrap()), Some(target));
+}
+
 #[test]
 fn test_target_data() {
     Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
@@ -401,7 +522,7 @@ fn test_write_target_machine_to_memory_buffer() {
         .write_to_memory_buffer(&module, FileType::Assembly)
         .unwrap();
 
-    assert!(!buffer.get_size() > 0);
+    assert!(buffer.get_size() > 0);
 
     let string = from_utf8(buffer.as_slice()).unwrap();
 
@@ -410,3 +531,313 @@ fn test_write_target_machine_to_memory_buffer() {
     assert!(string.contains("my_module"));
     assert!(string.contains(".section"));
 }
+
+#[test]
+fn test_write_host_object_file() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&triple).unwrap();
+    let target_machine = target
+        .create_target_machine(
+            &triple,
+            "generic",
+            "",
+            OptimizationLevel::Default,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+    builder.build_return(None);
+
+    let mut path = temp_dir();
+
+    path.push("temp_host.o");
+
+    assert!(target_machine.write_to_file(&module, FileType::Object, &path).is_ok());
+
+    let mut contents = Vec::new();
+    let mut file = File::open(&path).expect("Could not open temp file");
+
+    file.read_to_end(&mut contents).expect("Unable to verify written file");
+
+    let is_elf = contents.starts_with(b"\x7fELF");
+    let is_mach_o = contents.starts_with(&[0xcf, 0xfa, 0xed, 0xfe]) || contents.starts_with(&[0xce, 0xfa, 0xed, 0xfe]);
+    let is_coff = contents.starts_with(&[0x64, 0x86])
+        || contents.starts_with(&[0x4c, 0x01])
+        || contents.starts_with(&[0x64, 0xaa]);
+
+    assert!(is_elf || is_mach_o || is_coff, "Unexpected object file header");
+
+    let buffer = target_machine
+        .write_to_memory_buffer(&module, FileType::Object)
+        .unwrap();
+
+    assert_eq!(&buffer.as_slice()[..4], &contents[..4]);
+
+    remove_file(&path).unwrap();
+}
+
+#[llvm_versions(7.0..=latest)]
+#[test]
+fn test_host_cpu_target_machine_object() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let host_cpu_name = TargetMachine::get_host_cpu_name();
+    let host_cpu_features = TargetMachine::get_host_cpu_features();
+    let host_cpu_name = host_cpu_name.to_str().unwrap();
+    let host_cpu_features = host_cpu_features.to_str().unwrap();
+
+    assert!(!host_cpu_name.is_empty());
+
+    let triple = TargetMachine::get_default_triple();
+
+    assert_ne!(triple.as_str().to_str(), Ok(host_cpu_name));
+
+    let target = Target::from_triple(&triple).unwrap();
+    let target_machine = target
+        .create_target_machine(
+            &triple,
+            host_cpu_name,
+            host_cpu_features,
+            OptimizationLevel::Aggressive,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+
+    assert_eq!(target_machine.get_cpu().to_str(), Ok(host_cpu_name));
+    assert_eq!(target_machine.get_feature_string().to_str(), Ok(host_cpu_features));
+
+    let context = Context::create();
+    let module = context.create_module("native");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("square", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let x = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let square = builder.build_int_mul(x, x, "square");
+
+    builder.build_return(Some(&square));
+
+    let buffer = target_machine
+        .write_to_memory_buffer(&module, FileType::Object)
+        .unwrap();
+
+    assert!(buffer.get_size() > 0);
+}
+
+#[test]
+fn test_reloc_modes_address_global() {
+    Target::initialize_x86(&InitializationConfig::default());
+
+    let target = Target::from_name("x86").unwrap();
+    let context = Context::create();
+    let module = context.create_module("reloc");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let counter = module.add_global(i32_type, None, "counter");
+
+    counter.set_linkage(Linkage::Internal);
+    counter.set_initializer(&i32_type.const_zero());
+
+    let fn_type = i32_type.fn_type(&[], false);
+    let fn_value = module.add_function("read_counter", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let value = builder.build_load(counter.as_pointer_value(), "value");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let value = builder.build_load(i32_type, counter.as_pointer_value(), "value");
+
+    builder.build_return(Some(&value));
+
+    let create_target_machine = |reloc_mode, code_model| {
+        target
+            .create_target_machine(
+                &TargetTriple::create("i686-pc-linux-gnu"),
+                "i686",
+                "",
+                OptimizationLevel::Default,
+                reloc_mode,
+                code_model,
+            )
+            .unwrap()
+    };
+    let assembly = |target_machine: &TargetMachine| {
+        let buffer = target_machine
+            .write_to_memory_buffer(&module, FileType::Assembly)
+            .unwrap();
+
+        from_utf8(buffer.as_slice()).unwrap().to_string()
+    };
+
+    // 32 bit x86 has no pc relative data addressing, so static code uses the absolute
+    // address while PIC computes it relative to the GOT
+    let static_machine = create_target_machine(RelocMode::Static, CodeModel::Small);
+    let static_assembly = assembly(&static_machine);
+
+    assert!(static_assembly.contains("movl\tcounter, %eax"), "{}", static_assembly);
+    assert!(!static_assembly.contains("GOTOFF"), "{}", static_assembly);
+
+    let pic_machine = create_target_machine(RelocMode::PIC, CodeModel::Small);
+    let pic_assembly = assembly(&pic_machine);
+
+    assert!(pic_assembly.contains("counter@GOTOFF"), "{}", pic_assembly);
+
+    for code_model in [
+        CodeModel::Default,
+        CodeModel::JITDefault,
+        CodeModel::Medium,
+        CodeModel::Large,
+    ] {
+        let target_machine = create_target_machine(RelocMode::PIC, code_model);
+        let buffer = target_machine
+            .write_to_memory_buffer(&module, FileType::Object)
+            .unwrap();
+
+        assert!(buffer.as_slice().starts_with(b"\x7fELF"), "{:?}", code_model);
+    }
+}
+
+#[test]
+fn test_write_object_formats_to_memory_buffer() {
+    Target::initialize_x86(&InitializationConfig::default());
+
+    let target = Target::from_name("x86-64").unwrap();
+    let context = Context::create();
+    let module = context.create_module("objects");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(None);
+
+    let triples: [(&str, &[u8]); 3] = [
+        ("x86_64-pc-linux-gnu", b"\x7fELF"),
+        ("x86_64-apple-darwin", &[0xcf, 0xfa, 0xed, 0xfe]),
+        ("x86_64-pc-windows-msvc", &[0x64, 0x86]),
+    ];
+
+    for (triple, magic) in triples {
+        let target_machine = target
+            .create_target_machine(
+                &TargetTriple::create(triple),
+                "x86-64",
+                "",
+                OptimizationLevel::Default,
+                RelocMode::Default,
+                CodeModel::Default,
+            )
+            .unwrap();
+        let buffer = target_machine
+            .write_to_memory_buffer(&module, FileType::Object)
+            .unwrap();
+
+        assert_eq!(buffer.get_size(), buffer.as_slice().len());
+        assert!(buffer.as_slice().starts_with(magic), "{}", triple);
+
+        let assembly = target_machine
+            .write_to_memory_buffer(&module, FileType::Assembly)
+            .unwrap();
+
+        assert!(from_utf8(assembly.as_slice()).unwrap().contains("my_fn"), "{}", triple);
+    }
+}
+
+#[test]
+fn test_target_machine_settings_accessors() {
+    Target::initialize_x86(&InitializationConfig::default());
+
+    let target = Target::from_name("x86").unwrap();
+    let target_machine = target
+        .create_target_machine(
+            &TargetTriple::create("i686-pc-windows-msvc"),
+            "pentium4",
+            "+sse2,-avx",
+            OptimizationLevel::Less,
+            RelocMode::Static,
+            CodeModel::Small,
+        )
+        .unwrap();
+
+    assert_eq!(target_machine.get_target(), target);
+    assert_eq!(
+        target_machine.get_triple(),
+        TargetTriple::create("i686-pc-windows-msvc")
+    );
+    assert_eq!(target_machine.get_cpu().to_str(), Ok("pentium4"));
+    assert_eq!(target_machine.get_feature_string().to_str(), Ok("+sse2,-avx"));
+
+    let default_machine = target
+        .create_target_machine(
+            &TargetTriple::create("i686-pc-linux-gnu"),
+            "",
+            "",
+            OptimizationLevel::Default,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+
+    assert_eq!(default_machine.get_triple(), TargetTriple::create("i686-pc-linux-gnu"));
+    assert_eq!(default_machine.get_cpu().to_str(), Ok(""));
+    assert_eq!(default_machine.get_feature_string().to_str(), Ok(""));
+}
+
+#[test]
+fn test_iterate_all_targets_finds_host() {
+    Target::initialize_all(&InitializationConfig::default());
+
+    let host_target = Target::from_triple(&TargetMachine::get_default_triple()).unwrap();
+    let mut names = Vec::new();
+    let mut found_host = false;
+    let mut target = Target::get_first();
+
+    while let Some(t) = target {
+        let name = t.get_name().to_str().unwrap().to_string();
+
+        assert!(!name.is_empty());
+        assert!(!names.contains(&name), "{} was visited twice", name);
+        assert!(!t.get_description().to_bytes().is_empty(), "{}", name);
+
+        if t == host_target {
+            found_host = true;
+        }
+
+        names.push(name);
+        target = t.get_next();
+    }
+
+    assert!(
+        found_host,
+        "host target {:?} not in {:?}",
+        host_target.get_name(),
+        names
+    );
+    assert!(names
+        .iter()
+        .any(|name| name == host_target.get_name().to_str().unwrap()));
+    assert!(host_target.has_jit());
+    assert!(host_target.has_target_machine());
+    assert!(host_target.has_asm_backend());
+}
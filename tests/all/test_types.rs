@@ -1,7 +1,8 @@
 use inkwell::context::Context;
+use inkwell::module::Linkage;
 use inkwell::types::BasicType;
 use inkwell::values::AnyValue;
-use inkwell::AddressSpace;
+use inkwell::{AddressSpace, OptimizationLevel};
 
 #[test]
 fn test_struct_type() {
@@ -455,3 +456,242 @@ fn test_ptr_address_space() {
 
     assert!(AddressSpace::try_from(1u32 << 24).is_err());
 }
+
+#[test]
+fn test_aggregate_types_as_globals() {
+    let context = Context::create();
+    let module = context.create_module("aggregates");
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+
+    let packed_type = context.struct_type(&[i8_type.into(), i32_type.into()], true);
+    let array_type = i32_type.array_type(4);
+    let vec_type = i32_type.vec_type(4);
+
+    assert!(packed_type.is_packed());
+    assert_eq!(packed_type.count_fields(), 2);
+    assert_eq!(packed_type.get_field_types(), vec![i8_type.into(), i32_type.into()]);
+    assert_eq!(array_type.len(), 4);
+    assert_eq!(array_type.get_element_type(), i32_type.into());
+    assert_eq!(vec_type.get_size(), 4);
+    assert_eq!(vec_type.get_element_type(), i32_type.into());
+
+    let packed_global = module.add_global(packed_type, None, "packed");
+    let array_global = module.add_global(array_type, None, "array");
+    let vec_global = module.add_global(vec_type, None, "vec");
+
+    packed_global.set_initializer(&packed_type.const_zero());
+    array_global.set_initializer(&array_type.const_zero());
+    vec_global.set_initializer(&vec_type.const_zero());
+
+    assert_eq!(packed_type.print_to_string().to_str(), Ok("<{ i8, i32 }>"));
+    assert_eq!(
+        context
+            .struct_type(&[i8_type.into(), i32_type.into()], false)
+            .print_to_string()
+            .to_str(),
+        Ok("{ i8, i32 }")
+    );
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("@packed = global <{ i8, i32 }> zeroinitializer"), "{}", ir);
+    assert!(ir.contains("@array = global [4 x i32] zeroinitializer"), "{}", ir);
+    assert!(ir.contains("@vec = global <4 x i32> zeroinitializer"), "{}", ir);
+}
+
+#[test]
+fn test_ptr_type_address_space_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("addrspace");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let global_address_space = AddressSpace::from(1u16);
+    let i8_global_ptr_type = i8_type.ptr_type(global_address_space);
+
+    assert_eq!(i8_global_ptr_type.get_address_space(), global_address_space);
+    assert_ne!(i8_global_ptr_type, i8_type.ptr_type(AddressSpace::default()));
+    assert_eq!(
+        i8_type.as_basic_type_enum().ptr_type(global_address_space),
+        i8_global_ptr_type
+    );
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert_eq!(i8_global_ptr_type.get_element_type().into_int_type(), i8_type);
+
+    let global = module.add_global(i8_type, Some(global_address_space), "device_byte");
+
+    global.set_initializer(&i8_type.const_zero());
+
+    assert_eq!(global.as_pointer_value().get_type(), i8_global_ptr_type);
+
+    let fn_type = i8_type.fn_type(&[i8_global_ptr_type.into()], false);
+    let fn_value = module.add_function("read", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(entry);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let byte = builder.build_load(ptr, "byte");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let byte = builder.build_load(i8_type, ptr, "byte");
+
+    builder.build_return(Some(&byte));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("@device_byte = addrspace(1) global i8 0"), "{}", ir);
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert!(ir.contains("define i8 @read(i8 addrspace(1)* %0)"), "{}", ir);
+}
+
+#[test]
+fn test_size_of_struct_for_malloc_jit() {
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Mirror {
+        a: i8,
+        b: i64,
+        c: i32,
+    }
+
+    let context = Context::create();
+    let module = context.create_module("size_of");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let i8_ptr_type = i8_type.ptr_type(AddressSpace::default());
+    let struct_type = context.struct_type(&[i8_type.into(), i64_type.into(), i32_type.into()], false);
+
+    assert!(context.opaque_struct_type("opaque").size_of().is_none());
+
+    let size = struct_type.size_of().unwrap();
+    let alignment = struct_type.get_alignment();
+
+    assert_eq!(size.get_type(), i64_type);
+    assert_eq!(alignment.get_type(), i64_type);
+
+    let malloc_type = i8_ptr_type.fn_type(&[i64_type.into()], false);
+    let malloc = module.add_function("malloc", malloc_type, Some(Linkage::External));
+    let free_type = context.void_type().fn_type(&[i8_ptr_type.into()], false);
+    let free = module.add_function("free", free_type, Some(Linkage::External));
+
+    for (name, value) in [("size", size), ("alignment", alignment)] {
+        let fn_value = module.add_function(name, i64_type.fn_type(&[], false), None);
+        let entry = context.append_basic_block(fn_value, "entry");
+
+        builder.position_at_end(entry);
+        builder.build_return(Some(&value));
+    }
+
+    let fn_value = module.add_function("allocate", i8_ptr_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let ptr = builder
+        .build_call(malloc, &[size.into()], "ptr")
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+
+    builder.build_return(Some(&ptr));
+
+    let fn_value = module.add_function("release", free_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_call(free, &[fn_value.get_first_param().unwrap().into()], "");
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let size = execution_engine
+            .get_function::<unsafe extern "C" fn() -> u64>("size")
+            .unwrap();
+        let alignment = execution_engine
+            .get_function::<unsafe extern "C" fn() -> u64>("alignment")
+            .unwrap();
+        let allocate = execution_engine
+            .get_function::<unsafe extern "C" fn() -> *mut u8>("allocate")
+            .unwrap();
+        let release = execution_engine
+            .get_function::<unsafe extern "C" fn(*mut u8)>("release")
+            .unwrap();
+
+        assert_eq!(size.call(), std::mem::size_of::<Mirror>() as u64);
+        assert_eq!(alignment.call(), std::mem::align_of::<Mirror>() as u64);
+
+        let ptr = allocate.call();
+
+        assert!(!ptr.is_null());
+
+        release.call(ptr);
+    }
+}
+
+#[test]
+fn test_recursive_named_struct() {
+    let context = Context::create();
+    let module = context.create_module("recursive");
+    let i32_type = context.i32_type();
+    let node_type = context.opaque_struct_type("Node");
+
+    assert!(node_type.is_opaque());
+    assert_eq!(node_type.get_name().unwrap().to_str(), Ok("Node"));
+    assert_eq!(context.get_struct_type("Node"), Some(node_type));
+
+    let node_ptr_type = node_type.ptr_type(AddressSpace::default());
+
+    assert!(node_type.set_body(&[i32_type.into(), node_ptr_type.into()], false));
+    assert!(!node_type.is_opaque());
+    assert!(!node_type.is_packed());
+    assert_eq!(node_type.count_fields(), 2);
+    assert_eq!(node_type.get_field_type_at_index(1), Some(node_ptr_type.into()));
+
+    // Setting the body of a struct which already has one reports it wasn't opaque
+    assert!(!node_type.set_body(&[i32_type.into(), node_ptr_type.into()], false));
+
+    let global = module.add_global(node_type, None, "head");
+
+    global.set_initializer(&node_type.const_zero());
+
+    let ir = module.to_string();
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert!(ir.contains("%Node = type { i32, %Node* }"), "{}", ir);
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    assert!(ir.contains("%Node = type { i32, ptr }"), "{}", ir);
+    assert!(ir.contains("@head = global %Node zeroinitializer"), "{}", ir);
+}
+
+#[test]
+fn test_custom_width_int_type_bounds() {
+    let context = Context::create();
+
+    assert_eq!(context.custom_width_int_type(1), context.bool_type());
+    assert_eq!(context.custom_width_int_type(128), context.i128_type());
+    assert_eq!(context.custom_width_int_type(1 << 23).get_bit_width(), 1 << 23);
+}
+
+#[test]
+#[should_panic(expected = "Integer bit width must be between 1 and 2^23 inclusive")]
+fn test_custom_width_int_type_zero_bits() {
+    let context = Context::create();
+
+    context.custom_width_int_type(0);
+}
+
+#[test]
+#[should_panic(expected = "Integer bit width must be between 1 and 2^23 inclusive")]
+fn test_custom_width_int_type_too_wide() {
+    let context = Context::create();
+
+    context.custom_width_int_type((1 << 23) + 1);
+}
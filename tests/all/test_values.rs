@@ -3,9 +3,9 @@ use inkwell::attributes::AttributeLoc;
 use inkwell::comdat::ComdatSelectionKind;
 use inkwell::context::Context;
 use inkwell::module::Linkage::*;
-use inkwell::types::{StringRadix, VectorType};
-use inkwell::values::{AnyValue, InstructionOpcode::*, FIRST_CUSTOM_METADATA_KIND_ID};
-use inkwell::{AddressSpace, DLLStorageClass, GlobalVisibility, ThreadLocalMode};
+use inkwell::types::{BasicType, StringRadix, VectorType};
+use inkwell::values::{AnyValue, BasicValue, InstructionOpcode::*, FIRST_CUSTOM_METADATA_KIND_ID};
+use inkwell::{AddressSpace, DLLStorageClass, GlobalVisibility, OptimizationLevel, ThreadLocalMode};
 
 use std::convert::TryFrom;
 
@@ -330,6 +330,46 @@ fn test_verify_fn() {
     // TODO: Verify other verify modes
 }
 
+#[test]
+fn test_verify_fn_missing_terminator() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("fns");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("fn", fn_type, None);
+
+    assert!(function.get_entry_basic_block().is_none());
+    assert!(function.get_basic_blocks().is_empty());
+
+    let entry = context.append_basic_block(function, "entry");
+    let exit = context.append_basic_block(function, "exit");
+
+    assert_eq!(function.get_entry_basic_block(), Some(entry));
+    assert_eq!(function.get_first_basic_block(), Some(entry));
+    assert_eq!(function.get_basic_blocks(), vec![entry, exit]);
+
+    builder.position_at_end(entry);
+
+    let param = function.get_first_param().unwrap().into_int_value();
+    let doubled = builder.build_int_add(param, param, "doubled");
+
+    // Neither block has a terminator yet
+    assert!(!function.verify(false));
+
+    builder.build_unconditional_branch(exit);
+
+    // The exit block still lacks one
+    assert!(!function.verify(false));
+    assert!(module.verify().is_err());
+
+    builder.position_at_end(exit);
+    builder.build_return(Some(&doubled));
+
+    assert!(function.verify(false));
+    assert!(module.verify().is_ok());
+}
+
 #[test]
 fn test_metadata() {
     let context = Context::create();
@@ -860,6 +900,273 @@ fn test_globals() {
     }
 }
 
+#[test]
+fn test_constant_global_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "table");
+
+    global.set_initializer(&i32_type.const_int(7, false));
+    global.set_linkage(Internal);
+
+    assert!(!global.is_constant());
+    assert!(global.to_string().contains("@table = internal global i32 7"));
+
+    global.set_constant(true);
+
+    assert!(global.is_constant());
+    assert!(global.to_string().contains("@table = internal constant i32 7"));
+
+    global.set_constant(false);
+
+    assert!(global.to_string().contains("@table = internal global i32 7"));
+}
+
+#[test]
+fn test_function_linkage_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("helper", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(None);
+
+    assert_eq!(fn_value.get_linkage(), External);
+    assert!(module.to_string().contains("define void @helper()"));
+
+    fn_value.set_linkage(Internal);
+
+    assert_eq!(fn_value.get_linkage(), Internal);
+    assert_eq!(fn_value.as_global_value().get_linkage(), Internal);
+    assert!(module.to_string().contains("define internal void @helper()"));
+
+    fn_value.as_global_value().set_linkage(LinkOnceODR);
+
+    assert_eq!(fn_value.get_linkage(), LinkOnceODR);
+    assert!(module.to_string().contains("define linkonce_odr"));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_linkage_round_trip() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "my_global");
+
+    global.set_initializer(&i32_type.const_zero());
+
+    let supported = [
+        Appending,
+        AvailableExternally,
+        Common,
+        External,
+        ExternalWeak,
+        Internal,
+        LinkOnceAny,
+        LinkOnceODR,
+        Private,
+        WeakAny,
+        WeakODR,
+    ];
+
+    for linkage in supported {
+        global.set_linkage(linkage);
+
+        assert_eq!(global.get_linkage(), linkage);
+    }
+
+    for linkage in [LinkerPrivate, LinkerPrivateWeak] {
+        global.set_linkage(External);
+        global.set_linkage(linkage);
+
+        assert_eq!(global.get_linkage(), Private);
+    }
+
+    for linkage in [Ghost, DLLImport, DLLExport, LinkOnceODRAutoHide] {
+        global.set_linkage(Internal);
+        global.set_linkage(linkage);
+
+        assert_eq!(global.get_linkage(), Internal);
+    }
+}
+
+#[test]
+fn test_set_initializer_on_external_global() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "forward");
+
+    assert!(global.is_declaration());
+    assert!(global.get_initializer().is_none());
+    assert!(global.to_string().contains("@forward = external global i32"));
+
+    let fn_value = module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+
+    assert!(fn_value.as_global_value().get_initializer().is_none());
+
+    global.set_initializer(&i32_type.const_int(5, false));
+
+    assert!(!global.is_declaration());
+    assert_eq!(
+        global.get_initializer().unwrap().into_int_value(),
+        i32_type.const_int(5, false)
+    );
+    assert!(global.to_string().contains("@forward = global i32 5"));
+
+    global.set_initializer(&i32_type.const_int(6, false));
+
+    assert!(global.to_string().contains("@forward = global i32 6"));
+}
+
+#[test]
+fn test_const_aggregate_global_initializers() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let elements = [
+        i32_type.const_int(1, false),
+        i32_type.const_int(2, false),
+        i32_type.const_int(3, false),
+    ];
+
+    let array = i32_type.const_array(&elements);
+    let array_global = module.add_global(array.get_type(), None, "array");
+
+    assert_eq!(array.get_type(), i32_type.array_type(3));
+
+    array_global.set_initializer(&array);
+
+    let point_type = context.opaque_struct_type("Point");
+
+    point_type.set_body(&[i32_type.into(), i32_type.into()], false);
+
+    let point = point_type.const_named_struct(&[elements[0].into(), elements[1].into()]);
+    let point_global = module.add_global(point_type, None, "point");
+
+    point_global.set_initializer(&point);
+
+    let packed = context.const_struct(&[i8_type.const_int(4, false).into(), elements[2].into()], true);
+    let packed_global = module.add_global(packed.get_type(), None, "packed");
+
+    assert!(packed.get_type().is_packed());
+
+    packed_global.set_initializer(&packed);
+
+    let vector = VectorType::const_vector(&elements);
+    let vector_global = module.add_global(vector.get_type(), None, "vector");
+
+    assert_eq!(vector.get_type(), i32_type.vec_type(3));
+
+    vector_global.set_initializer(&vector);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("@array = global [3 x i32] [i32 1, i32 2, i32 3]"), "{}", ir);
+    assert!(ir.contains("@point = global %Point { i32 1, i32 2 }"), "{}", ir);
+    assert!(
+        ir.contains("@packed = global <{ i8, i32 }> <{ i8 4, i32 3 }>"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("@vector = global <3 x i32> <i32 1, i32 2, i32 3>"),
+        "{}",
+        ir
+    );
+}
+
+#[test]
+fn test_const_string_global_initializer() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let greeting = "héllo";
+
+    let string = context.const_string(greeting.as_bytes(), false);
+    let string_null = context.const_string(greeting.as_bytes(), true);
+
+    assert_eq!(greeting.len(), 6);
+    assert_eq!(string.get_type(), context.i8_type().array_type(6));
+    assert_eq!(string_null.get_type(), context.i8_type().array_type(7));
+
+    let global = module.add_global(string_null.get_type(), None, "greeting");
+
+    global.set_initializer(&string_null);
+    global.set_constant(true);
+
+    assert!(module.verify().is_ok());
+    assert!(module
+        .to_string()
+        .contains("@greeting = constant [7 x i8] c\"h\\C3\\A9llo\\00\""));
+}
+
+#[test]
+fn test_section() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let function = module.add_function("my_fn", fn_type, None);
+    let global = module.add_global(context.i8_type(), None, "my_global");
+    let builder = context.create_builder();
+    let entry = context.append_basic_block(function, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(None);
+
+    assert_eq!(function.get_section(), None);
+    assert_eq!(global.get_section(), None);
+
+    function.set_section(Some(".mytext"));
+    global.set_section(Some(".mydata"));
+
+    assert_eq!(function.get_section().unwrap().to_str(), Ok(".mytext"));
+    assert_eq!(global.get_section().unwrap().to_str(), Ok(".mydata"));
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let ir = module.to_string();
+
+        assert!(ir.contains("define void @my_fn() section \".mytext\""));
+        assert!(ir.contains("@my_global = external global i8, section \".mydata\""));
+    }
+
+    // Empty sections are treated as unset
+    function.set_section(Some(""));
+    global.set_section(None);
+
+    assert_eq!(function.get_section(), None);
+    assert_eq!(global.get_section(), None);
+}
+
+#[test]
+fn test_thread_local_global_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "tls_state");
+
+    global.set_initializer(&i32_type.const_zero());
+
+    assert!(!global.to_string().contains("thread_local"));
+
+    global.set_thread_local_mode(Some(ThreadLocalMode::GeneralDynamicTLSModel));
+
+    assert!(global.is_thread_local());
+    assert!(global.to_string().contains("@tls_state = thread_local global i32 0"));
+
+    global.set_thread_local_mode(Some(ThreadLocalMode::LocalExecTLSModel));
+
+    assert!(global.to_string().contains("thread_local(localexec)"));
+}
+
 #[test]
 fn test_phi_values() {
     let context = Context::create();
@@ -1211,6 +1518,74 @@ fn test_vectors() {
     assert!(module.verify().is_ok());
 }
 
+#[test]
+fn test_vector_shuffle_reverse_jit() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("simd");
+    let i32_type = context.i32_type();
+    let vec_type = i32_type.vec_type(4);
+    let fn_type = i32_type.fn_type(&[i32_type.into(); 4], false);
+    let fn_value = module.add_function("last_lane", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+
+    let mut vector = vec_type.get_undef();
+
+    for (lane, param) in fn_value.get_param_iter().enumerate() {
+        let index = i32_type.const_int(lane as u64, false);
+
+        vector = builder.build_insert_element(vector, param, index, "insert");
+    }
+
+    let mask = VectorType::const_vector(&[
+        i32_type.const_int(3, false),
+        i32_type.const_int(2, false),
+        i32_type.const_int(1, false),
+        i32_type.const_int(0, false),
+    ]);
+    let reversed = builder.build_shuffle_vector(vector, vec_type.get_undef(), mask, "reversed");
+
+    assert_eq!(reversed.get_type(), vec_type);
+
+    let first_lane = builder.build_extract_element(reversed, i32_type.const_zero(), "first_lane");
+
+    builder.build_return(Some(&first_lane));
+
+    assert!(module.verify().is_ok());
+    assert!(module
+        .to_string()
+        .contains("shufflevector <4 x i32> %insert3, <4 x i32> undef, <4 x i32> <i32 3, i32 2, i32 1, i32 0>"));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let last_lane = execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32, i32, i32) -> i32>("last_lane")
+            .unwrap();
+
+        assert_eq!(last_lane.call(1, 2, 3, 4), 4);
+        assert_eq!(last_lane.call(-1, 0, 0, -7), -7);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Shuffle mask must be a constant vector of i32")]
+fn test_vector_shuffle_non_constant_mask() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("simd");
+    let vec_type = context.i32_type().vec_type(2);
+    let fn_type = vec_type.fn_type(&[vec_type.into()], false);
+    let fn_value = module.add_function("shuffle", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let vector = fn_value.get_first_param().unwrap().into_vector_value();
+
+    builder.position_at_end(entry);
+    builder.build_shuffle_vector(vector, vector, vector, "shuffled");
+}
+
 #[test]
 fn test_aggregate_returns() {
     let context = Context::create();
@@ -1265,3 +1640,307 @@ fn test_constant_expression() {
     assert!(expr.is_const());
     assert!(!expr.is_constant_int());
 }
+
+#[test]
+fn test_function_params_named_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i64_type.into()], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+
+    assert_eq!(fn_value.count_params(), 2);
+    assert!(fn_value.get_nth_param(2).is_none());
+    assert!(fn_value.get_nth_param(u32::MAX).is_none());
+
+    let lhs = fn_value.get_nth_param(0).unwrap();
+    let rhs = fn_value.get_nth_param(1).unwrap();
+
+    lhs.set_name("lhs");
+    rhs.set_name("rhs");
+
+    assert_eq!(fn_value.get_params(), vec![lhs, rhs]);
+    assert_eq!(fn_value.get_param_iter().collect::<Vec<_>>(), vec![lhs, rhs]);
+    assert_eq!(lhs.into_int_value().get_name().to_str(), Ok("lhs"));
+    assert_eq!(rhs.get_type(), i64_type.into());
+
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(Some(&lhs));
+
+    assert!(fn_value.verify(false));
+    assert!(module.to_string().contains("define i32 @my_fn(i32 %lhs, i64 %rhs)"));
+}
+
+#[test]
+fn test_int_and_float_constants_jit() {
+    let context = Context::create();
+    let module = context.create_module("consts");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let i128_type = context.i128_type();
+    let f64_type = context.f64_type();
+
+    let forty_two = i32_type.const_int(42, false);
+    let minus_one = i32_type.const_int(-1i64 as u64, true);
+
+    assert!(forty_two.is_const());
+    assert_eq!(forty_two.get_zero_extended_constant(), Some(42));
+    assert_eq!(minus_one, i32_type.const_all_ones());
+    assert_eq!(minus_one.get_sign_extended_constant(), Some(-1));
+    assert_eq!(i32_type.const_zero().get_zero_extended_constant(), Some(0));
+    assert_eq!(i8_type.const_int(300, false).get_zero_extended_constant(), Some(44));
+    assert_eq!(i128_type.const_int(-1i64 as u64, true), i128_type.const_all_ones());
+    assert_ne!(i128_type.const_int(-1i64 as u64, false), i128_type.const_all_ones());
+    assert_eq!(f64_type.const_float(1.5).get_constant(), Some((1.5, false)));
+
+    let int_fn = module.add_function("forty_two", i32_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(int_fn, "entry");
+
+    builder.position_at_end(entry);
+
+    let slot = builder.build_alloca(i32_type, "slot");
+
+    builder.build_store(slot, forty_two);
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    let loaded = builder.build_load(slot, "loaded");
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    let loaded = builder.build_load(i32_type, slot, "loaded");
+
+    builder.build_return(Some(&loaded));
+
+    let all_ones_fn = module.add_function("all_ones", i32_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(all_ones_fn, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(Some(&i32_type.const_all_ones()));
+
+    let float_fn = module.add_function("one_and_a_half", f64_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(float_fn, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(Some(&f64_type.const_float(1.5)));
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let forty_two = execution_engine
+            .get_function::<unsafe extern "C" fn() -> i32>("forty_two")
+            .unwrap();
+        let all_ones = execution_engine
+            .get_function::<unsafe extern "C" fn() -> i32>("all_ones")
+            .unwrap();
+        let one_and_a_half = execution_engine
+            .get_function::<unsafe extern "C" fn() -> f64>("one_and_a_half")
+            .unwrap();
+
+        assert_eq!(forty_two.call(), 42);
+        assert_eq!(all_ones.call(), -1);
+        assert_eq!(one_and_a_half.call(), 1.5);
+    }
+}
+
+#[test]
+fn test_delete_function() {
+    let context = Context::create();
+    let module = context.create_module("fns");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let first = module.add_function("first", fn_type, None);
+    let doomed = module.add_function("doomed", fn_type, None);
+    let last = module.add_function("last", fn_type, None);
+
+    assert_eq!(doomed.count_basic_blocks(), 0);
+
+    let entry = context.append_basic_block(doomed, "entry");
+
+    context.append_basic_block(doomed, "exit");
+    builder.position_at_end(entry);
+    builder.build_return(None);
+
+    assert_eq!(doomed.count_basic_blocks(), 2);
+    assert_eq!(module.get_function("doomed"), Some(doomed));
+
+    unsafe { doomed.delete() };
+
+    assert!(module.get_function("doomed").is_none());
+    assert_eq!(module.get_functions().collect::<Vec<_>>(), vec![first, last]);
+    assert_eq!(first.get_next_function(), Some(last));
+    assert!(module.verify().is_ok());
+
+    // The name is free to be reused
+    let redefined = module.add_function("doomed", fn_type, None);
+
+    assert_eq!(redefined.get_name().to_str(), Ok("doomed"));
+    assert_eq!(module.get_function("doomed"), Some(redefined));
+}
+
+#[test]
+fn test_call_conventions_in_ir() {
+    const FAST_CALL_CONV: u32 = 8;
+
+    let context = Context::create();
+    let module = context.create_module("call_conv");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let callee = module.add_function("callee", fn_type, None);
+    let caller = module.add_function("caller", fn_type, None);
+
+    assert_eq!(callee.get_call_conventions(), 0);
+
+    callee.set_call_conventions(FAST_CALL_CONV);
+
+    assert_eq!(callee.get_call_conventions(), FAST_CALL_CONV);
+
+    let entry = context.append_basic_block(callee, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(Some(&i32_type.const_int(42, false)));
+
+    let entry = context.append_basic_block(caller, "entry");
+
+    builder.position_at_end(entry);
+
+    let call_site = builder.build_call(callee, &[], "call");
+
+    assert_eq!(call_site.get_call_convention(), 0);
+
+    call_site.set_call_convention(callee.get_call_conventions());
+
+    assert_eq!(call_site.get_call_convention(), FAST_CALL_CONV);
+
+    builder.build_return(Some(&call_site.try_as_basic_value().left().unwrap()));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("define fastcc i32 @callee()"), "{}", ir);
+    assert!(ir.contains("%call = call fastcc i32 @callee()"), "{}", ir);
+    assert!(ir.contains("define i32 @caller()"), "{}", ir);
+}
+
+#[test]
+fn test_visibility_and_dll_storage_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("visibility");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_value = module.add_function("hidden_fn", context.void_type().fn_type(&[], false), None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(None);
+
+    let hidden_global = module.add_global(i32_type, None, "hidden_global");
+    let protected_global = module.add_global(i32_type, None, "protected_global");
+    let exported_global = module.add_global(i32_type, None, "exported_global");
+
+    for global in [hidden_global, protected_global, exported_global] {
+        global.set_initializer(&i32_type.const_zero());
+    }
+
+    fn_value.as_global_value().set_visibility(GlobalVisibility::Hidden);
+    hidden_global.set_visibility(GlobalVisibility::Hidden);
+    protected_global.set_visibility(GlobalVisibility::Protected);
+    exported_global.set_dll_storage_class(DLLStorageClass::Export);
+
+    assert_eq!(fn_value.as_global_value().get_visibility(), GlobalVisibility::Hidden);
+    assert_eq!(protected_global.get_visibility(), GlobalVisibility::Protected);
+    assert_eq!(exported_global.get_visibility(), GlobalVisibility::Default);
+    assert_eq!(exported_global.get_dll_storage_class(), DLLStorageClass::Export);
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+
+    assert!(ir.contains("define hidden void @hidden_fn()"), "{}", ir);
+    assert!(ir.contains("@hidden_global = hidden global i32 0"), "{}", ir);
+    assert!(ir.contains("@protected_global = protected global i32 0"), "{}", ir);
+    assert!(ir.contains("@exported_global = dllexport global i32 0"), "{}", ir);
+}
+
+#[test]
+fn test_null_pointer_global_and_return_jit() {
+    let context = Context::create();
+    let module = context.create_module("null_ptr");
+    let builder = context.create_builder();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::default());
+    let null = i8_ptr_type.const_null();
+
+    assert!(null.is_null());
+    assert!(null.is_const());
+    assert_eq!(
+        i8_ptr_type.as_basic_type_enum().const_zero(),
+        null.as_basic_value_enum()
+    );
+    assert_eq!(i8_ptr_type.get_context(), context);
+
+    let global = module.add_global(i8_ptr_type, None, "ptr_field");
+
+    global.set_initializer(&null);
+
+    let fn_type = i8_ptr_type.fn_type(&[], false);
+    let fn_value = module.add_function("get_null", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(Some(&null));
+
+    let ir = module.to_string();
+
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0")))]
+    assert!(ir.contains("@ptr_field = global i8* null"), "{}", ir);
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0"))]
+    assert!(ir.contains("@ptr_field = global ptr null"), "{}", ir);
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let get_null = execution_engine
+            .get_function::<unsafe extern "C" fn() -> *const i8>("get_null")
+            .unwrap();
+
+        assert!(get_null.call().is_null());
+    }
+}
+
+#[llvm_versions(12.0..=latest)]
+#[test]
+fn test_poison_constants() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+    let pair_type = context.struct_type(&[i32_type.into(), f64_type.into()], false);
+
+    let int_poison = i32_type.get_poison();
+    let float_poison = f64_type.get_poison();
+    let ptr_poison = i32_type.ptr_type(AddressSpace::default()).get_poison();
+    let array_poison = i32_type.array_type(2).get_poison();
+    let vec_poison = i32_type.vec_type(4).get_poison();
+    let struct_poison = pair_type.get_poison();
+
+    assert!(int_poison.is_poison());
+    assert!(float_poison.is_poison());
+    assert!(ptr_poison.is_poison());
+    assert!(array_poison.is_poison());
+    assert!(vec_poison.is_poison());
+    assert!(struct_poison.is_poison());
+
+    // Poison is a kind of undef, but not the other way around
+    assert!(int_poison.is_undef());
+    assert!(!i32_type.get_undef().is_poison());
+    assert_eq!(
+        pair_type.as_basic_type_enum().get_poison(),
+        struct_poison.as_basic_value_enum()
+    );
+    assert_eq!(int_poison.print_to_string().to_str(), Ok("i32 poison"));
+}